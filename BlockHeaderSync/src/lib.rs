@@ -1,5 +1,6 @@
 #![no_std]
 
+use access_control::Role;
 use eth_address::EthAddress;
 use header::*;
 use public_key::*;
@@ -11,15 +12,15 @@ const MIN_CONSENSUS_SIZE: usize = 3;
 elrond_wasm::imports!();
 
 #[elrond_wasm_derive::contract]
-pub trait BlockHeaderSync {
+pub trait BlockHeaderSync: access_control::AccessControlModule + pause::PauseModule {
     #[init]
     fn init(&self) {}
 
     // endpoints
 
-    #[only_owner]
     #[endpoint(syncGenesisHeader)]
     fn sync_genesis_header(&self, header: Header, book_keepers: Vec<PublicKey>) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
         require!(
             self.consensus_peers().is_empty(),
             "Genesis header already set"
@@ -40,6 +41,7 @@ pub trait BlockHeaderSync {
         book_keepers: Vec<PublicKey>,
         sig_data: Vec<Signature>,
     ) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
         require!(
             !self.consensus_peers().is_empty(),
             "Must set genesis header first"
@@ -77,6 +79,8 @@ pub trait BlockHeaderSync {
 
     #[endpoint(verifyHeader)]
     fn verify_header(&self, header_hash: H256, sig_data: Vec<Signature>) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
+
         let prev_consensus = self.consensus_peers().get();
         let min_sigs = self.get_min_signatures(prev_consensus.len());
 
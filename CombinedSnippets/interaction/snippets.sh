@@ -6,6 +6,17 @@ WRAPPED_EGLD_TOKEN_IDENTIFIER=0x5745474c442d643736326565
 # update after issue
 WRAPPED_ETH_TOKEN_IDENTIFIER=0x574554482d333365313865
 
+# update after the TransactionRelayer deploy, "0x" followed by the hex encoded address
+TRANSACTION_RELAYER_ADDRESS=0x0000000000000000000000000000000000000000000000000000000000000000
+
+# Ethereum (just an example, could be any chain), with its CrossChainManager contract and token addresses
+OTHER_CHAIN_ID=0x0A
+OTHER_CHAIN_PROXY=0x0139472eff6886771a982f3083da5d421f24c291
+WRAPPED_EGLD_OTHER_CHAIN_ASSET_HASH=0x81e63888228dc81ca60d69e10139472eff688677
+WRAPPED_ETH_OTHER_CHAIN_ASSET_HASH=0x0000000000000000000000000000000000000000
+
+ESDT_SYSTEM_SC_ADDRESS=erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u
+
 # No need to update this, as it's always the same poly tx, which in turn means the same hash
 # Hash for TX from Elrond to another chain
 FROM_ERD_TX_HASH=0xd95c06a936c765969c42846432d41268fd73c7a169e10ad1543050a4431edb04
@@ -69,6 +80,12 @@ deployEsdtTokenManager() {
 
     sleep 40
 
+    loadNonce
+    setLocalRoles
+    storeIncrementNonce
+
+    sleep 40
+
     echo "Wrapped eGLD token identifier:"
     getWrappedEgldTokenIdentifier
 }
@@ -76,6 +93,12 @@ deployEsdtTokenManager() {
 finalizeSetup() {
     source ../CrossChainManagement/interaction/snippets.sh
 
+    loadNonce
+    deployTransactionRelayer
+    storeIncrementNonce
+
+    sleep 10
+
     loadNonce
     setTokenManagerAddress
     storeIncrementNonce
@@ -83,13 +106,13 @@ finalizeSetup() {
     sleep 10
 
     loadNonce
-    addTokenToWhitelist ${WRAPPED_EGLD_TOKEN_IDENTIFIER}
+    setProxyHash ${OTHER_CHAIN_ID} ${OTHER_CHAIN_PROXY}
     storeIncrementNonce
 
     sleep 10
 
     loadNonce
-    addAddressToApprovedlist 0x0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1 # Alice's address
+    setAssetHash ${WRAPPED_EGLD_TOKEN_IDENTIFIER} ${OTHER_CHAIN_ID} ${WRAPPED_EGLD_OTHER_CHAIN_ASSET_HASH}
     storeIncrementNonce
 }
 
@@ -103,39 +126,10 @@ queryEsdtTokenManager() {
     echo "Wrapped eGLD token identifier:"
     getWrappedEgldTokenIdentifier
 
-    echo "Total wrapped eGLD:"
-    getTokenAmount ${WRAPPED_EGLD_TOKEN_IDENTIFIER}
-
     echo "Total locked eGLD:"
     getLockedEgldBalance
 }
 
-mintMoreWrappedEgld() {
-    source ../EsdtTokenManager/interaction/snippets.sh
-
-    loadNonce
-    mintTokens ${WRAPPED_EGLD_TOKEN_IDENTIFIER} 0x8AC7230489E80000 # 10 wrapped eGLD (10 * 10^18)
-    storeIncrementNonce
-
-    sleep 40
-
-    echo "Total wrapped eGLD:"
-    getTokenAmount ${WRAPPED_EGLD_TOKEN_IDENTIFIER}
-}
-
-burnWrappedEgld() {
-    source ../EsdtTokenManager/interaction/snippets.sh
-
-    loadNonce
-    burnTokens ${WRAPPED_EGLD_TOKEN_IDENTIFIER} 0x8AC7230489E80000 # 10 wrapped eGLD (10 * 10^18)
-    storeIncrementNonce
-
-    sleep 40
-
-    echo "Total wrapped eGLD:"
-    getTokenAmount ${WRAPPED_EGLD_TOKEN_IDENTIFIER}
-}
-
 # Cross Chain Management
 
 getNextPendingCrossChainTransation() {
@@ -200,36 +194,34 @@ sendFiveEgldToAnotherChain() {
 
 # Scenario 2
 
-# Issue wrapped ETH and add to token whitelist
+# Issue wrapped ETH, let the TransactionRelayer mint and burn it, and map it to ETH
 # Alice receives 6 wrapped ETH
 # Alice sends 2 wrapped ETH to an offchain account (4 ETH left)
 
+# Wrapped ETH is only minted by the TransactionRelayer, so it's issued with no initial supply
 issueWrappedEth() {
-    source ../EsdtTokenManager/interaction/snippets.sh
+    source ../CrossChainManagement/interaction/snippets.sh
 
     loadNonce
-    issueToken 0x57726170706564455448 0x57455448 0x0A 0x00
+    erdpy --verbose contract call ${ESDT_SYSTEM_SC_ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=60000000 --value=50000000000000000 --function="issue" --arguments 0x57726170706564455448 0x57455448 0x00 0x12 0x63616e4164645370656369616c526f6c6573 0x74727565 --send --proxy=${PROXY} --chain=${CHAIN_ID}
     storeIncrementNonce
-
-    sleep 40
-
-    echo "Wrapped ETH token identifier:"
-    getLastIssuedTokenIdentifier
 }
 
-addWrappedEthToWhitelist() {
+# Remember to update WRAPPED_ETH_TOKEN_IDENTIFIER variable after the issue is complete
+setWrappedEthRoles() {
     source ../CrossChainManagement/interaction/snippets.sh
 
     loadNonce
-    addTokenToWhitelist ${WRAPPED_ETH_TOKEN_IDENTIFIER}
+    erdpy --verbose contract call ${ESDT_SYSTEM_SC_ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=60000000 --function="setSpecialRole" --arguments ${WRAPPED_ETH_TOKEN_IDENTIFIER} ${TRANSACTION_RELAYER_ADDRESS} 0x45534454526f6c654c6f63616c4d696e74 0x45534454526f6c654c6f63616c4275726e --send --proxy=${PROXY} --chain=${CHAIN_ID}
     storeIncrementNonce
 }
 
-getTotalWrappedEth() {
-    source ../EsdtTokenManager/interaction/snippets.sh
+setWrappedEthAssetHash() {
+    source ../CrossChainManagement/interaction/snippets.sh
 
-    echo "Total wrapped ETH:"
-    getTokenAmount ${WRAPPED_ETH_TOKEN_IDENTIFIER}
+    loadNonce
+    setAssetHash ${WRAPPED_ETH_TOKEN_IDENTIFIER} ${OTHER_CHAIN_ID} ${WRAPPED_ETH_OTHER_CHAIN_ASSET_HASH}
+    storeIncrementNonce
 }
 
 receiveSixWrappedEth() {
@@ -26,21 +26,52 @@ deploy() {
     echo "Smart contract address: ${ADDRESS}"
 }
 
+# The TransactionRelayer has to be built first
+deployTransactionRelayer() {
+    TRANSACTION_RELAYER_CODE=0x$(xxd -p ${PROJECT_HARDCODED}/../TransactionRelayer/output/transaction-relayer.wasm | tr -d '\n')
+
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=200000000 --function="deployTransactionRelayerContract" --arguments ${TRANSACTION_RELAYER_CODE} --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
+# The baseline relayer was not deployed as upgradeable, and has no views, so its registries are read from its storage.
+# Arguments: chain id (decimal)
+exportLegacyChainProxy() {
+    TRANSACTION_RELAYER_ADDRESS=$(erdpy --verbose contract query ${ADDRESS} --function="getTransactionRelayerContractAddress" --proxy=${PROXY} | jq -r '.[0].hex')
+    TRANSACTION_RELAYER_ADDRESS_BECH32=$(erdpy wallet bech32 --encode ${TRANSACTION_RELAYER_ADDRESS})
+    KEY=$(echo -n "proxyHashMap" | xxd -p)$(printf '%016x' $1)
+
+    curl -s ${PROXY}/address/${TRANSACTION_RELAYER_ADDRESS_BECH32}/key/${KEY} | jq -r '.data.value'
+}
+
+# Arguments: token identifier (e.g. WETH-123456), chain id (decimal)
+exportLegacyAssetHash() {
+    TRANSACTION_RELAYER_ADDRESS=$(erdpy --verbose contract query ${ADDRESS} --function="getTransactionRelayerContractAddress" --proxy=${PROXY} | jq -r '.[0].hex')
+    TRANSACTION_RELAYER_ADDRESS_BECH32=$(erdpy wallet bech32 --encode ${TRANSACTION_RELAYER_ADDRESS})
+    KEY=$(echo -n "assetHashMap" | xxd -p)$(printf '%08x' ${#1})$(echo -n $1 | xxd -p)$(printf '%016x' $2)
+
+    curl -s ${PROXY}/address/${TRANSACTION_RELAYER_ADDRESS_BECH32}/key/${KEY} | jq -r '.data.value'
+}
+
+# Export every chain proxy and asset hash first, as they're not reachable through this contract afterwards
+retireLegacyTransactionRelayer() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="retireLegacyTransactionRelayer" --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
 setTokenManagerAddress() {
     ESDT_TOKEN_MANAGER_ADDRESS=$(erdpy data load --key=address-testnet-esdtTokenManager)
     ESDT_TOKEN_MANAGER_ADDRESS_DECODED=$(erdpy wallet bech32 --decode ${ESDT_TOKEN_MANAGER_ADDRESS})
 
-    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="setTokenManagementContractAddress" --arguments 0x${ESDT_TOKEN_MANAGER_ADDRESS_DECODED} --send --proxy=${PROXY} --chain=${CHAIN_ID}
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="setTransactionRelayerEgldTokenManager" --arguments 0x${ESDT_TOKEN_MANAGER_ADDRESS_DECODED} --send --proxy=${PROXY} --chain=${CHAIN_ID}
 }
 
-# Arguments: Token identifier
-addTokenToWhitelist() {
-    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="addTokenToWhitelist" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN_ID}
+# Arguments: chain id, proxy contract on that chain
+setProxyHash() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="setTransactionRelayerProxyHash" --arguments $1 $2 --send --proxy=${PROXY} --chain=${CHAIN_ID}
 }
 
-# Arguments: Address
-addAddressToApprovedlist() {
-    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="addAddressToApprovedlist" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN_ID}
+# Arguments: token identifier, chain id, the token's asset hash on that chain
+setAssetHash() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=50000000 --function="setTransactionRelayerAssetHash" --arguments $1 $2 $3 --send --proxy=${PROXY} --chain=${CHAIN_ID}
 }
 
 # Arguments: token identifier, amount, target chain id, destination contract address, method name, method args
@@ -0,0 +1,183 @@
+use access_control::Role;
+
+elrond_wasm::imports!();
+
+/// Elrond contracts that may be called by inbound cross-chain transactions,
+/// together with the methods each of them exposes to other chains,
+/// and the Elrond contracts that may send outbound cross-chain transactions
+#[elrond_wasm_derive::module]
+pub trait AppRegistryModule: access_control::AccessControlModule {
+    // endpoints - admin-only
+
+    #[endpoint(registerApplication)]
+    fn register_application(
+        &self,
+        app_address: Address,
+        #[var_args] method_names: VarArgs<BoxedBytes>,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.blockchain().is_smart_contract(&app_address),
+            "Application address is not a smart contract address"
+        );
+        require!(!method_names.is_empty(), "No methods provided");
+
+        self.add_application(&app_address, &method_names.into_vec());
+
+        Ok(())
+    }
+
+    #[endpoint(unregisterApplication)]
+    fn unregister_application(&self, app_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.remove_application(&app_address),
+            "Application not registered"
+        );
+
+        Ok(())
+    }
+
+    /// The contract on the given chain which is allowed to call the application
+    #[endpoint(setApplicationSourceContract)]
+    fn set_application_source_contract(
+        &self,
+        app_address: Address,
+        chain_id: u64,
+        source_contract: BoxedBytes,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.registered_applications().contains(&app_address),
+            "Application not registered"
+        );
+        require!(
+            !source_contract.is_empty(),
+            "Source contract address cannot be empty"
+        );
+
+        self.application_source_contract(&app_address, chain_id)
+            .set(&source_contract);
+
+        Ok(())
+    }
+
+    #[endpoint(addAllowedSender)]
+    fn add_allowed_sender(&self, sender_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.blockchain().is_smart_contract(&sender_address),
+            "Sender address is not a smart contract address"
+        );
+        require!(
+            self.allowed_senders().insert(sender_address),
+            "Sender already allowed"
+        );
+
+        Ok(())
+    }
+
+    #[endpoint(removeAllowedSender)]
+    fn remove_allowed_sender(&self, sender_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.allowed_senders().remove(&sender_address),
+            "Sender not allowed"
+        );
+
+        Ok(())
+    }
+
+    // views
+
+    #[view(getRegisteredApplications)]
+    fn get_registered_applications(&self) -> MultiResultVec<Address> {
+        self.registered_applications()
+            .iter()
+            .collect::<Vec<Address>>()
+            .into()
+    }
+
+    #[view(getApplicationMethods)]
+    fn get_application_methods(&self, app_address: Address) -> MultiResultVec<BoxedBytes> {
+        self.application_methods(&app_address)
+            .iter()
+            .collect::<Vec<BoxedBytes>>()
+            .into()
+    }
+
+    #[view(getAllowedSenders)]
+    fn get_allowed_senders(&self) -> MultiResultVec<Address> {
+        self.allowed_senders()
+            .iter()
+            .collect::<Vec<Address>>()
+            .into()
+    }
+
+    #[view(getOutboundTxCount)]
+    fn get_outbound_tx_count(&self, sender_address: Address) -> u64 {
+        self.outbound_tx_count(&sender_address).get()
+    }
+
+    #[view(getApplicationSourceContract)]
+    fn get_application_source_contract(&self, app_address: Address, chain_id: u64) -> BoxedBytes {
+        self.application_source_contract(&app_address, chain_id)
+            .get()
+    }
+
+    // private
+
+    fn add_application(&self, app_address: &Address, method_names: &[BoxedBytes]) {
+        self.registered_applications().insert(app_address.clone());
+
+        let mut methods_mapper = self.application_methods(app_address);
+        for method_name in method_names {
+            methods_mapper.insert(method_name.clone());
+        }
+    }
+
+    /// Returns false if the application was not registered
+    fn remove_application(&self, app_address: &Address) -> bool {
+        if !self.registered_applications().remove(app_address) {
+            return false;
+        }
+
+        let mut methods_mapper = self.application_methods(app_address);
+        let method_names: Vec<BoxedBytes> = methods_mapper.iter().collect();
+        for method_name in &method_names {
+            methods_mapper.remove(method_name);
+        }
+
+        true
+    }
+
+    fn is_application_method_allowed(
+        &self,
+        app_address: &Address,
+        method_name: &BoxedBytes,
+    ) -> bool {
+        self.registered_applications().contains(app_address)
+            && self.application_methods(app_address).contains(method_name)
+    }
+
+    // storage
+
+    #[storage_mapper("registeredApplications")]
+    fn registered_applications(&self) -> SetMapper<Self::Storage, Address>;
+
+    #[storage_mapper("applicationMethods")]
+    fn application_methods(&self, app_address: &Address) -> SetMapper<Self::Storage, BoxedBytes>;
+
+    #[storage_mapper("applicationSourceContract")]
+    fn application_source_contract(
+        &self,
+        app_address: &Address,
+        chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, BoxedBytes>;
+
+    #[storage_mapper("allowedSenders")]
+    fn allowed_senders(&self) -> SetMapper<Self::Storage, Address>;
+
+    #[storage_mapper("outboundTxCount")]
+    fn outbound_tx_count(&self, sender_address: &Address) -> SingleValueMapper<Self::Storage, u64>;
+}
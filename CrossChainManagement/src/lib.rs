@@ -1,6 +1,7 @@
 #![no_std]
 #![allow(non_snake_case)]
 
+use access_control::Role;
 use elrond_wasm::elrond_codec::TopEncode;
 use header::Header;
 use merkle_proof::MerkleProof;
@@ -9,11 +10,78 @@ use transaction::*;
 
 elrond_wasm::imports!();
 
+pub mod app_registry;
+pub mod outbound_accumulator;
+pub mod tx_status;
+
+/// Version of the storage layout. Bump when a release changes the layout,
+/// and add the corresponding step in `migrate_storage_from`.
+pub const CURRENT_STORAGE_VERSION: u32 = 3;
+
+/// Maximum number of transactions returned by a single getOutboundTxsByChain call
+pub const MAX_OUTBOUND_TXS_PAGE_SIZE: usize = 100;
+
+mod change_owner_proxy {
+    elrond_wasm::imports!();
+
+    /// Built-in function, which the owner of a contract can call on it
+    #[elrond_wasm_derive::proxy]
+    pub trait ChangeOwner {
+        #[endpoint(ChangeOwnerAddress)]
+        fn change_owner_address(&self, new_owner: Address);
+    }
+}
+
 #[elrond_wasm_derive::contract]
-pub trait CrossChainManagement {
-    // TODO: make upgrade-friendly
+pub trait CrossChainManagement:
+    access_control::AccessControlModule
+    + app_registry::AppRegistryModule
+    + outbound_accumulator::OutboundAccumulatorModule
+    + pause::PauseModule
+    + tx_status::TxStatusModule
+{
+    /// On upgrade, already set values are kept. Use the owner setters to change them.
+    /// Upgrades from the baseline contract list the relayer's (chain ID, proxy hash) pairs,
+    /// as the baseline relayer does not expose them.
     #[init]
-    fn init(&self, header_sync_contract_address: Address, own_chain_id: u64) -> SCResult<()> {
+    fn init(
+        &self,
+        header_sync_contract_address: Address,
+        own_chain_id: u64,
+        #[var_args] transaction_relayer_chain_proxies: VarArgs<MultiArg2<u64, BoxedBytes>>,
+    ) -> SCResult<()> {
+        if self.header_sync_contract_address().is_empty() {
+            require!(
+                self.blockchain()
+                    .is_smart_contract(&header_sync_contract_address),
+                "Provided HeaderSync address is not a smart contract address"
+            );
+
+            self.header_sync_contract_address()
+                .set(&header_sync_contract_address);
+        }
+        if self.own_chain_id().is_empty() {
+            self.own_chain_id().set(&own_chain_id);
+        }
+
+        let transaction_relayer_chain_proxies: Vec<(u64, BoxedBytes)> =
+            transaction_relayer_chain_proxies
+                .into_vec()
+                .into_iter()
+                .map(|chain_proxy| chain_proxy.into_tuple())
+                .collect();
+
+        self.migrate_storage(&transaction_relayer_chain_proxies)
+    }
+
+    // endpoints - admins and asset managers
+
+    #[endpoint(setHeaderSyncContractAddress)]
+    fn set_header_sync_contract_address(
+        &self,
+        header_sync_contract_address: Address,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
         require!(
             self.blockchain()
                 .is_smart_contract(&header_sync_contract_address),
@@ -22,16 +90,35 @@ pub trait CrossChainManagement {
 
         self.header_sync_contract_address()
             .set(&header_sync_contract_address);
+
+        Ok(())
+    }
+
+    /// Outbound txs are tracked by the own chain ID, so it can only be changed before any is created
+    #[endpoint(setOwnChainId)]
+    fn set_own_chain_id(&self, own_chain_id: u64) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.cross_chain_tx_id().get() == 0,
+            "Cannot change chain ID after outbound transactions were created"
+        );
+
         self.own_chain_id().set(&own_chain_id);
 
         Ok(())
     }
 
-    // endpoints - owner-only
+    /// Runs the pending storage migrations, in case they were not run on upgrade
+    #[endpoint(migrateStorage)]
+    fn migrate_storage_endpoint(&self) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        self.migrate_storage(&[])
+    }
 
-    #[only_owner]
     #[endpoint(deployTransactionRelayerContract)]
     fn deploy_transaction_relayer_contract(&self, contract_code: BoxedBytes) -> SCResult<Address> {
+        self.require_role(Role::Admin)?;
         require!(
             self.transaction_relayer_contract_address().is_empty(),
             "Transaction Relayer SC already deployed"
@@ -40,18 +127,111 @@ pub trait CrossChainManagement {
         let deploy_gas = self.blockchain().get_gas_left() / 2;
         let opt_address = self
             .transaction_relayer_proxy(Address::zero())
-            .init()
+            .init(VarArgs::from(Vec::new()))
             .with_gas_limit(deploy_gas)
-            .deploy_contract(&contract_code, CodeMetadata::DEFAULT);
+            .deploy_contract(&contract_code, CodeMetadata::UPGRADEABLE);
 
         let transaction_relayer_address = opt_address.ok_or("Transaction Relayer deploy failed")?;
         self.transaction_relayer_contract_address()
             .set(&transaction_relayer_address);
+        self.add_application(
+            &transaction_relayer_address,
+            &[
+                transaction_relayer::UNLOCK_METHOD_NAME.into(),
+                transaction_relayer::UNLOCK_NFT_METHOD_NAME.into(),
+            ],
+        );
+        self.allowed_senders()
+            .insert(transaction_relayer_address.clone());
 
         Ok(transaction_relayer_address)
     }
 
-    #[only_owner]
+    /// Upgrades the relayer's code in place, keeping its storage.
+    /// Only works for relayers deployed as upgradeable.
+    /// Relayers using the legacy registries need their (chain ID, token ID) pairs listed.
+    #[endpoint(upgradeTransactionRelayerContract)]
+    fn upgrade_transaction_relayer_contract(
+        &self,
+        contract_code: BoxedBytes,
+        #[var_args] legacy_assets: VarArgs<MultiArg2<u64, TokenIdentifier>>,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        self.require_transaction_relayer_deployed()?;
+        require!(
+            !self.legacy_transaction_relayer().get(),
+            "Legacy Transaction Relayer cannot be upgraded, retire it and deploy a new one"
+        );
+
+        let mut arg_buffer = ArgBuffer::new();
+        for legacy_asset in legacy_assets.into_vec() {
+            let (chain_id, token_id) = legacy_asset.into_tuple();
+            push_top_encoded_arg(&mut arg_buffer, &chain_id);
+            push_top_encoded_arg(&mut arg_buffer, &token_id);
+        }
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.send().upgrade_contract(
+            &tx_relayer_address,
+            &Self::BigUint::zero(),
+            &contract_code,
+            CodeMetadata::UPGRADEABLE,
+            &arg_buffer,
+        );
+
+        Ok(())
+    }
+
+    /// Forgets the baseline relayer, which was not deployed as upgradeable, so a new one can be deployed.
+    /// The baseline relayer holds no tokens, as it burns on lock and mints on unlock.
+    /// Its chain proxies and asset hashes have to be exported beforehand, and set again on the new relayer.
+    #[endpoint(retireLegacyTransactionRelayer)]
+    fn retire_legacy_transaction_relayer(&self) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        self.require_transaction_relayer_deployed()?;
+        require!(
+            self.legacy_transaction_relayer().get(),
+            "Transaction Relayer is not legacy"
+        );
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_contract_address().clear();
+        self.legacy_transaction_relayer().clear();
+        self.allowed_senders().remove(&tx_relayer_address);
+        self.remove_application(&tx_relayer_address);
+
+        self.legacy_transaction_relayer_retired_event(&tx_relayer_address);
+
+        Ok(())
+    }
+
+    /// Hands the relayer over to a new owner (e.g. a new CrossChainManagement SC).
+    /// Once the change succeeds, the relayer is forgotten, and no longer reachable through this contract.
+    #[endpoint(transferTransactionRelayerOwnership)]
+    fn transfer_transaction_relayer_ownership(
+        &self,
+        new_owner: Address,
+    ) -> SCResult<AsyncCall<Self::SendApi>> {
+        self.require_role(Role::Admin)?;
+
+        self.require_transaction_relayer_deployed()?;
+        require!(!new_owner.is_zero(), "New owner cannot be the zero address");
+        require!(
+            new_owner != self.blockchain().get_sc_address(),
+            "Relayer is already owned by this contract"
+        );
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+
+        Ok(self
+            .change_owner_proxy(tx_relayer_address.clone())
+            .change_owner_address(new_owner)
+            .async_call()
+            .with_callback(self.callbacks().change_owner_callback(&tx_relayer_address)))
+    }
+
     #[endpoint(setTransactionRelayerAssetHash)]
     fn set_transaction_relayer_asset_hash(
         &self,
@@ -59,6 +239,8 @@ pub trait CrossChainManagement {
         to_chain_id: u64,
         other_chain_asset_hash: BoxedBytes,
     ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
         self.require_transaction_relayer_deployed()?;
 
         let tx_relayer_address = self.transaction_relayer_contract_address().get();
@@ -69,16 +251,20 @@ pub trait CrossChainManagement {
         Ok(())
     }
 
-    #[only_owner]
     #[endpoint(setTransactionRelayerProxyHash)]
     fn set_transaction_relayer_proxy_hash(
         &self,
         chain_id: u64,
         proxy_hash: BoxedBytes,
     ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
         self.require_transaction_relayer_deployed()?;
 
         let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.application_source_contract(&tx_relayer_address, chain_id)
+            .set(&proxy_hash);
+
         self.transaction_relayer_proxy(tx_relayer_address)
             .set_chain_proxy_hash(chain_id, proxy_hash)
             .execute_on_dest_context();
@@ -86,7 +272,257 @@ pub trait CrossChainManagement {
         Ok(())
     }
 
-    #[endpoint(getMerkleProof)]
+    #[endpoint(removeTransactionRelayerAssetHash)]
+    fn remove_transaction_relayer_asset_hash(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .remove_asset_hash(token_id, to_chain_id)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(removeTransactionRelayerChain)]
+    fn remove_transaction_relayer_chain(&self, chain_id: u64) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.application_source_contract(&tx_relayer_address, chain_id)
+            .clear();
+
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .remove_chain(chain_id)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(setTransactionRelayerAssetDecimals)]
+    fn set_transaction_relayer_asset_decimals(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        local_decimals: u8,
+        remote_decimals: u8,
+        dust_policy: transaction_relayer::DustPolicy,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .set_asset_decimals(
+                token_id,
+                chain_id,
+                local_decimals,
+                remote_decimals,
+                dust_policy,
+            )
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    /// Roles on the relayer, for example fee collectors
+    #[endpoint(grantTransactionRelayerRole)]
+    fn grant_transaction_relayer_role(&self, role: Role, address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &role);
+        push_top_encoded_arg(&mut arg_buffer, &address);
+
+        self.call_transaction_relayer_module_endpoint(b"grantRole", &arg_buffer)
+    }
+
+    #[endpoint(revokeTransactionRelayerRole)]
+    fn revoke_transaction_relayer_role(&self, role: Role, address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &role);
+        push_top_encoded_arg(&mut arg_buffer, &address);
+
+        self.call_transaction_relayer_module_endpoint(b"revokeRole", &arg_buffer)
+    }
+
+    #[endpoint(setTransactionRelayerFee)]
+    fn set_transaction_relayer_fee(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+        flat_fee: Self::BigUint,
+        fee_basis_points: u32,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &token_id);
+        push_top_encoded_arg(&mut arg_buffer, &to_chain_id);
+        push_top_encoded_arg(&mut arg_buffer, &flat_fee);
+        push_top_encoded_arg(&mut arg_buffer, &fee_basis_points);
+
+        self.call_transaction_relayer_module_endpoint(b"setFee", &arg_buffer)
+    }
+
+    #[endpoint(setTransactionRelayerTransferLimits)]
+    fn set_transaction_relayer_transfer_limits(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        min_amount: Self::BigUint,
+        max_amount: Self::BigUint,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &token_id);
+        push_top_encoded_arg(&mut arg_buffer, &chain_id);
+        push_top_encoded_arg(&mut arg_buffer, &min_amount);
+        push_top_encoded_arg(&mut arg_buffer, &max_amount);
+
+        self.call_transaction_relayer_module_endpoint(b"setTransferLimits", &arg_buffer)
+    }
+
+    #[endpoint(setTransactionRelayerDailyCap)]
+    fn set_transaction_relayer_daily_cap(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        direction: transaction_relayer::limits::TransferDirection,
+        cap: Self::BigUint,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &token_id);
+        push_top_encoded_arg(&mut arg_buffer, &chain_id);
+        push_top_encoded_arg(&mut arg_buffer, &direction);
+        push_top_encoded_arg(&mut arg_buffer, &cap);
+
+        self.call_transaction_relayer_module_endpoint(b"setDailyCap", &arg_buffer)
+    }
+
+    #[endpoint(setTransactionRelayerTokenMode)]
+    fn set_transaction_relayer_token_mode(
+        &self,
+        token_id: TokenIdentifier,
+        mode: transaction_relayer::TokenMode,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .set_token_mode(token_id, mode)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(registerTransactionRelayerBridgeReceiver)]
+    fn register_transaction_relayer_bridge_receiver(
+        &self,
+        receiver_address: Address,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .register_bridge_receiver(receiver_address)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(unregisterTransactionRelayerBridgeReceiver)]
+    fn unregister_transaction_relayer_bridge_receiver(
+        &self,
+        receiver_address: Address,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .unregister_bridge_receiver(receiver_address)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(refundTransactionRelayerFailedUnlock)]
+    fn refund_transaction_relayer_failed_unlock(&self, failed_unlock_id: u64) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .refund_failed_unlock(failed_unlock_id)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(setTransactionRelayerEgldTokenManager)]
+    fn set_transaction_relayer_egld_token_manager(
+        &self,
+        token_manager_address: Address,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        self.require_transaction_relayer_deployed()?;
+
+        let tx_relayer_address = self.transaction_relayer_contract_address().get();
+        self.transaction_relayer_proxy(tx_relayer_address)
+            .set_egld_token_manager(token_manager_address)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    #[endpoint(unpauseTransactionRelayer)]
+    fn unpause_transaction_relayer(
+        &self,
+        unpause_inbound: bool,
+        unpause_outbound: bool,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        self.call_transaction_relayer_pause_endpoint(
+            pause::UNPAUSE_ENDPOINT_NAME,
+            unpause_inbound,
+            unpause_outbound,
+        )
+    }
+
+    // endpoints - pausers
+
+    /// The relayer is owned by this contract, so its pausers act through this endpoint
+    #[endpoint(pauseTransactionRelayer)]
+    fn pause_transaction_relayer(&self, pause_inbound: bool, pause_outbound: bool) -> SCResult<()> {
+        self.require_role(Role::Pauser)?;
+
+        self.call_transaction_relayer_pause_endpoint(
+            pause::PAUSE_ENDPOINT_NAME,
+            pause_inbound,
+            pause_outbound,
+        )
+    }
+
+    // views
+
+    #[view(getMerkleProof)]
     fn get_merkle_proof(&self, proof: BoxedBytes, root: H256) -> SCResult<BoxedBytes> {
         let merkle_proof = MerkleProof::from_bytes(self.crypto(), &proof)?;
         let proof_root = merkle_proof.get_proof_root();
@@ -96,6 +532,85 @@ pub trait CrossChainManagement {
         Ok(merkle_proof.into_raw_leaf())
     }
 
+    /// Returns the raw leaf, the leaf hash and the computed root, without comparing against any root
+    #[view(getMerkleProofDetails)]
+    fn get_merkle_proof_details(
+        &self,
+        proof: BoxedBytes,
+    ) -> SCResult<MultiResult3<BoxedBytes, H256, H256>> {
+        let merkle_proof = MerkleProof::from_bytes(self.crypto(), &proof)?;
+        let leaf_hash = merkle_proof.get_leaf_hash();
+        let proof_root = merkle_proof.get_proof_root();
+
+        Ok((merkle_proof.into_raw_leaf(), leaf_hash, proof_root).into())
+    }
+
+    /// Checks each proof against the given root. Malformed proofs are reported as invalid.
+    #[view(verifyMerkleProofBatch)]
+    fn verify_merkle_proof_batch(
+        &self,
+        root: H256,
+        #[var_args] proofs: VarArgs<BoxedBytes>,
+    ) -> MultiResultVec<bool> {
+        let mut results = Vec::with_capacity(proofs.len());
+        for proof in proofs.into_vec() {
+            let is_valid = match MerkleProof::from_bytes(self.crypto(), &proof) {
+                Result::Ok(merkle_proof) => merkle_proof.get_proof_root() == root,
+                Result::Err(_) => false,
+            };
+
+            results.push(is_valid);
+        }
+
+        results.into()
+    }
+
+    #[view(getOutboundTx)]
+    fn get_outbound_tx(&self, cross_chain_tx_id: u64) -> SCResult<Transaction> {
+        let tx_mapper = self.outbound_tx(cross_chain_tx_id);
+        require!(!tx_mapper.is_empty(), "Outbound transaction not found");
+
+        Ok(tx_mapper.get())
+    }
+
+    #[view(getOutboundTxByHash)]
+    fn get_outbound_tx_by_hash(&self, tx_hash: H256) -> SCResult<Transaction> {
+        let tx_id_mapper = self.outbound_tx_id_by_hash(&tx_hash);
+        require!(!tx_id_mapper.is_empty(), "Outbound transaction not found");
+
+        self.get_outbound_tx(tx_id_mapper.get())
+    }
+
+    #[view(getOutboundTxCountByChain)]
+    fn get_outbound_tx_count_by_chain(&self, to_chain_id: u64) -> usize {
+        self.outbound_tx_ids_by_chain(to_chain_id).len()
+    }
+
+    /// Outbound transactions sent to the given chain, in creation order.
+    /// Returns at most `count` transactions (capped at MAX_OUTBOUND_TXS_PAGE_SIZE),
+    /// starting from the `from_index`-th transaction (0-based).
+    #[view(getOutboundTxsByChain)]
+    fn get_outbound_txs_by_chain(
+        &self,
+        to_chain_id: u64,
+        from_index: usize,
+        count: usize,
+    ) -> MultiResultVec<Transaction> {
+        let tx_ids_mapper = self.outbound_tx_ids_by_chain(to_chain_id);
+        let total_txs = tx_ids_mapper.len();
+        let page_size = core::cmp::min(count, MAX_OUTBOUND_TXS_PAGE_SIZE);
+        let end_index = core::cmp::min(from_index.saturating_add(page_size), total_txs);
+
+        let mut txs = Vec::new();
+        for index in from_index..end_index {
+            // VecMapper indexes start from 1
+            let tx_id = tx_ids_mapper.get(index + 1);
+            txs.push(self.outbound_tx(tx_id).get());
+        }
+
+        txs.into()
+    }
+
     // Transaction from other chain -> Elrond
     #[endpoint(verifyHeaderAndExecuteTx)]
     fn verify_header_and_execute_tx(
@@ -106,84 +621,75 @@ pub trait CrossChainManagement {
         raw_current_header: BoxedBytes,
         header_sigs: Vec<Signature>,
     ) -> SCResult<()> {
-        self.require_transaction_relayer_deployed()?;
-
-        let tx_header_hash = Header::hash_raw_header(self.crypto(), &raw_tx_header);
-        let tx_header = Header::top_decode(raw_tx_header.as_slice())?;
-
-        let current_header_hash = Header::hash_raw_header(self.crypto(), &raw_current_header);
-        let current_header = Header::top_decode(raw_current_header.as_slice())?;
-
-        let block_header_sync_address = self.header_sync_contract_address().get();
-
-        let epoch_start_height = self
-            .block_header_sync_proxy(block_header_sync_address.clone())
-            .current_epoch_start_height()
-            .execute_on_dest_context();
-
-        // since the verify method returns SCResult<()>, the whole call will crash if the verify fails
-        if tx_header.height >= epoch_start_height {
-            self.block_header_sync_proxy(block_header_sync_address)
-                .verify_header(tx_header_hash, header_sigs)
-                .execute_on_dest_context();
-        } else {
-            self.block_header_sync_proxy(block_header_sync_address)
-                .verify_header(current_header_hash.clone(), header_sigs)
-                .execute_on_dest_context();
+        self.require_inbound_not_paused()?;
 
-            let current_header_merkle_proof =
-                MerkleProof::from_bytes(self.crypto(), &current_header_proof)?;
+        let cross_state_root = self.verify_tx_header(
+            &raw_tx_header,
+            &current_header_proof,
+            &raw_current_header,
+            header_sigs,
+        )?;
 
-            require!(
-                current_header_merkle_proof.get_proof_root() == current_header.block_root,
-                "Current header merkle proof failed: Block Root does not match"
-            );
+        self.execute_tx(&tx_proof, &cross_state_root)
+    }
 
-            let proven_hash = current_header_merkle_proof.into_raw_leaf();
-            require!(
-                proven_hash.as_slice() == current_header_hash.as_bytes(),
-                "Current header merkle proof failed: hash does not match proven value"
-            );
-        }
+    /// Same as verifyHeaderAndExecuteTx, but only for headers that were already verified.
+    /// Only the tx merkle proof is checked, against the stored cross state root.
+    #[endpoint(executeTxWithKnownHeader)]
+    fn execute_tx_with_known_header(
+        &self,
+        tx_proof: BoxedBytes,
+        header_height: u32,
+    ) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
 
-        let tx_merkle_proof = MerkleProof::from_bytes(self.crypto(), &tx_proof)?;
+        let cross_state_root = self.get_known_cross_state_root(header_height)?;
 
-        require!(
-            tx_merkle_proof.get_proof_root() == tx_header.cross_state_root,
-            "Tx merkle proof failed: Cross State Root does not match"
-        );
+        self.execute_tx(&tx_proof, &cross_state_root)
+    }
 
-        let tx_raw = tx_merkle_proof.into_raw_leaf();
-        let to_merkle_value = ToMerkleValue::top_decode(tx_raw.as_slice())?;
+    /// Batch version of verifyHeaderAndExecuteTx. All txs must be proven against the same header.
+    #[endpoint(verifyHeaderAndExecuteTxBatch)]
+    fn verify_header_and_execute_tx_batch(
+        &self,
+        raw_tx_header: BoxedBytes,
+        current_header_proof: BoxedBytes,
+        raw_current_header: BoxedBytes,
+        header_sigs: Vec<Signature>,
+        #[var_args] tx_proofs: VarArgs<BoxedBytes>,
+    ) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
+        require!(!tx_proofs.is_empty(), "No tx proofs provided");
 
-        require!(
-            to_merkle_value.tx.method_name == transaction_relayer::UNLOCK_METHOD_NAME.into(),
-            "Only unlock method may be called"
-        );
+        let cross_state_root = self.verify_tx_header(
+            &raw_tx_header,
+            &current_header_proof,
+            &raw_current_header,
+            header_sigs,
+        )?;
 
-        require!(
-            !self.does_tx_exist(to_merkle_value.from_chain_id, &to_merkle_value.poly_tx_hash),
-            "Transaction was already processed"
-        );
-        self.set_tx_exists(to_merkle_value.from_chain_id, &to_merkle_value.poly_tx_hash);
+        self.execute_tx_batch(tx_proofs.into_vec(), &cross_state_root)
+    }
 
-        self.receive_tx_event(&to_merkle_value.tx);
+    /// Batch version of executeTxWithKnownHeader
+    #[endpoint(executeTxBatchWithKnownHeader)]
+    fn execute_tx_batch_with_known_header(
+        &self,
+        header_height: u32,
+        #[var_args] tx_proofs: VarArgs<BoxedBytes>,
+    ) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
+        require!(!tx_proofs.is_empty(), "No tx proofs provided");
 
-        let transaction_relayer_address = self.transaction_relayer_contract_address().get();
-        self.transaction_relayer_proxy(transaction_relayer_address)
-            .unlock(
-                to_merkle_value.tx.method_args,
-                to_merkle_value.tx.from_contract_address,
-                to_merkle_value.from_chain_id,
-            )
-            .execute_on_dest_context();
+        let cross_state_root = self.get_known_cross_state_root(header_height)?;
 
-        Ok(())
+        self.execute_tx_batch(tx_proofs.into_vec(), &cross_state_root)
     }
 
     // endpoints
 
     /// Transactions from Elrond -> other_chain
+    /// method_args is an opaque payload, which is decoded by the destination contract
     #[payable("*")]
     #[endpoint(createCrossChainTx)]
     fn create_cross_chain_tx(
@@ -191,16 +697,20 @@ pub trait CrossChainManagement {
         to_chain_id: u64,
         to_contract_address: BoxedBytes,
         method_name: BoxedBytes,
-        method_args: TransactionArgs<Self::BigUint>,
+        method_args: BoxedBytes,
     ) -> SCResult<()> {
-        self.require_transaction_relayer_deployed()?;
-        
+        self.require_outbound_not_paused()?;
+
         let caller = self.blockchain().get_caller();
-        let transaction_relayer_address = self.transaction_relayer_contract_address().get();
         require!(
-            caller == transaction_relayer_address,
-            "Only TransactionRelayer SC may call this function"
+            self.allowed_senders().contains(&caller),
+            "Caller is not an allowed sender application"
         );
+        let method_args = if self.is_legacy_transaction_relayer(&caller) {
+            self.legacy_args_to_raw(&method_args)?
+        } else {
+            method_args
+        };
 
         let own_chain_id = self.own_chain_id().get();
         require!(
@@ -208,10 +718,14 @@ pub trait CrossChainManagement {
             "Must send to a chain other than Elrond"
         );
 
+        let cross_chain_tx_id = self.get_and_increment_cross_chain_tx_id();
+        let mut serialized_tx_id = Vec::new();
+        let _ = cross_chain_tx_id.top_encode(&mut serialized_tx_id);
+
         let mut tx = Transaction {
             source_chain_tx_hash: H256::zero(),
-            cross_chain_tx_id: self.get_and_increment_cross_chain_tx_id(),
-            from_contract_address: transaction_relayer_address.into_boxed_bytes(),
+            cross_chain_tx_id: serialized_tx_id.into(),
+            from_contract_address: caller.as_bytes().into(),
             to_chain_id,
             to_contract_address,
             method_name,
@@ -224,12 +738,44 @@ pub trait CrossChainManagement {
             "Transaction was already processed"
         );
         self.set_tx_exists(own_chain_id, &tx.source_chain_tx_hash);
+        self.change_tx_status(
+            own_chain_id,
+            &tx.source_chain_tx_hash,
+            TransactionStatus::Pending,
+        )?;
+        self.outbound_tx_count(&caller).update(|count| *count += 1);
+
+        self.outbound_tx(cross_chain_tx_id).set(&tx);
+        self.outbound_tx_id_by_hash(&tx.source_chain_tx_hash)
+            .set(&cross_chain_tx_id);
+        self.outbound_tx_ids_by_chain(to_chain_id)
+            .push(&cross_chain_tx_id);
+        self.accumulate_outbound_tx(&tx.source_chain_tx_hash)?;
 
         self.create_tx_event(&tx);
 
         Ok(())
     }
 
+    /// Used by the Poly relayers to report the progress of an outbound tx.
+    /// Allowed transitions: Pending -> InProgress, Pending/InProgress -> Executed/Rejected
+    #[endpoint(acknowledgeOutboundTx)]
+    fn acknowledge_outbound_tx(
+        &self,
+        tx_hash: H256,
+        new_status: TransactionStatus,
+    ) -> SCResult<()> {
+        self.require_role(Role::Relayer)?;
+
+        let own_chain_id = self.own_chain_id().get();
+        require!(
+            self.does_tx_exist(own_chain_id, &tx_hash),
+            "Unknown outbound transaction"
+        );
+
+        self.change_tx_status(own_chain_id, &tx_hash, new_status)
+    }
+
     // private
 
     fn require_transaction_relayer_deployed(&self) -> SCResult<()> {
@@ -241,14 +787,12 @@ pub trait CrossChainManagement {
         Ok(())
     }
 
-    fn get_and_increment_cross_chain_tx_id(&self) -> BoxedBytes {
+    fn get_and_increment_cross_chain_tx_id(&self) -> u64 {
         self.cross_chain_tx_id().update(|tx_id| {
-            let mut serialized = Vec::new();
-            let _ = tx_id.top_encode(&mut serialized);
-
+            let current_tx_id = *tx_id;
             *tx_id += 1;
 
-            serialized.into()
+            current_tx_id
         })
     }
 
@@ -260,6 +804,404 @@ pub trait CrossChainManagement {
         self.tx_exists(from_chain_id, poly_tx_hash).set(&true);
     }
 
+    /// Verifies the tx header (directly, or through the current header and a block root proof),
+    /// and returns its cross state root, which is also stored for later use.
+    /// Headers whose cross state root was already verified are not checked again.
+    fn verify_tx_header(
+        &self,
+        raw_tx_header: &BoxedBytes,
+        current_header_proof: &BoxedBytes,
+        raw_current_header: &BoxedBytes,
+        header_sigs: Vec<Signature>,
+    ) -> SCResult<H256> {
+        let tx_header = Header::top_decode(raw_tx_header.as_slice())?;
+
+        let known_root_mapper = self.verified_cross_state_root(tx_header.height);
+        if !known_root_mapper.is_empty() && known_root_mapper.get() == tx_header.cross_state_root {
+            return Ok(tx_header.cross_state_root);
+        }
+
+        let current_header_hash = Header::hash_raw_header(self.crypto(), raw_current_header);
+        let current_header = Header::top_decode(raw_current_header.as_slice())?;
+
+        let block_header_sync_address = self.header_sync_contract_address().get();
+
+        let epoch_start_height = self
+            .block_header_sync_proxy(block_header_sync_address.clone())
+            .current_epoch_start_height()
+            .execute_on_dest_context();
+
+        // since the verify method returns SCResult<()>, the whole call will crash if the verify fails
+        if tx_header.height >= epoch_start_height {
+            let tx_header_hash = Header::hash_raw_header(self.crypto(), raw_tx_header);
+            self.block_header_sync_proxy(block_header_sync_address)
+                .verify_header(tx_header_hash, header_sigs)
+                .execute_on_dest_context();
+        } else {
+            self.block_header_sync_proxy(block_header_sync_address)
+                .verify_header(current_header_hash.clone(), header_sigs)
+                .execute_on_dest_context();
+
+            self.require_header_proven(
+                current_header_proof,
+                &current_header.block_root,
+                raw_tx_header,
+            )?;
+        }
+
+        self.verified_cross_state_root(tx_header.height)
+            .set(&tx_header.cross_state_root);
+        self.header_verified_event(tx_header.height, &tx_header.cross_state_root);
+
+        Ok(tx_header.cross_state_root)
+    }
+
+    /// Checks that the current header's block root proves the tx header.
+    /// The proven leaf is the tx header's hash, not the current header's.
+    fn require_header_proven(
+        &self,
+        current_header_proof: &BoxedBytes,
+        current_header_block_root: &H256,
+        raw_tx_header: &BoxedBytes,
+    ) -> SCResult<()> {
+        let tx_header_hash = Header::hash_raw_header(self.crypto(), raw_tx_header);
+        let current_header_merkle_proof =
+            MerkleProof::from_bytes(self.crypto(), current_header_proof)?;
+
+        require!(
+            &current_header_merkle_proof.get_proof_root() == current_header_block_root,
+            "Current header merkle proof failed: Block Root does not match"
+        );
+
+        let proven_hash = current_header_merkle_proof.into_raw_leaf();
+        require!(
+            proven_hash.as_slice() == tx_header_hash.as_bytes(),
+            "Current header merkle proof failed: hash does not match proven value"
+        );
+
+        Ok(())
+    }
+
+    fn get_known_cross_state_root(&self, header_height: u32) -> SCResult<H256> {
+        let known_root_mapper = self.verified_cross_state_root(header_height);
+        require!(
+            !known_root_mapper.is_empty(),
+            "No verified header known for the given height"
+        );
+
+        Ok(known_root_mapper.get())
+    }
+
+    fn execute_tx(&self, tx_proof: &BoxedBytes, cross_state_root: &H256) -> SCResult<()> {
+        let to_merkle_value = self.prepare_tx(tx_proof, cross_state_root)?;
+
+        self.dispatch_tx(to_merkle_value)
+    }
+
+    /// Executes each tx whose proof is valid. Invalid proofs do not revert the whole batch,
+    /// they are only reported through the batchTxFailed event.
+    /// Note: a failure inside the TransactionRelayer still reverts the whole call.
+    fn execute_tx_batch(
+        &self,
+        tx_proofs: Vec<BoxedBytes>,
+        cross_state_root: &H256,
+    ) -> SCResult<()> {
+        for to_merkle_value in self.prepare_tx_batch(&tx_proofs, cross_state_root) {
+            self.dispatch_tx(to_merkle_value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Prepares each tx of the batch, and returns the valid ones, in order
+    fn prepare_tx_batch(
+        &self,
+        tx_proofs: &[BoxedBytes],
+        cross_state_root: &H256,
+    ) -> Vec<ToMerkleValue> {
+        let mut valid_txs = Vec::new();
+        for (index, tx_proof) in tx_proofs.iter().enumerate() {
+            match self.prepare_tx(tx_proof, cross_state_root) {
+                Ok(to_merkle_value) => {
+                    self.batch_tx_executed_event(
+                        index,
+                        to_merkle_value.from_chain_id,
+                        &to_merkle_value.poly_tx_hash,
+                    );
+                    valid_txs.push(to_merkle_value);
+                }
+                Err(err) => {
+                    self.batch_tx_failed_event(index, &BoxedBytes::from(err.as_bytes()));
+                }
+            }
+        }
+
+        valid_txs
+    }
+
+    /// Checks the tx proof and the tx itself, and marks the tx as processed
+    fn prepare_tx(
+        &self,
+        tx_proof: &BoxedBytes,
+        cross_state_root: &H256,
+    ) -> SCResult<ToMerkleValue> {
+        let tx_merkle_proof = MerkleProof::from_bytes(self.crypto(), tx_proof)?;
+
+        require!(
+            &tx_merkle_proof.get_proof_root() == cross_state_root,
+            "Tx merkle proof failed: Cross State Root does not match"
+        );
+
+        let tx_raw = tx_merkle_proof.into_raw_leaf();
+        let to_merkle_value = ToMerkleValue::top_decode(tx_raw.as_slice())?;
+
+        self.validate_inbound_tx(&to_merkle_value)?;
+
+        require!(
+            !self.does_tx_exist(to_merkle_value.from_chain_id, &to_merkle_value.poly_tx_hash),
+            "Transaction was already processed"
+        );
+        self.set_tx_exists(to_merkle_value.from_chain_id, &to_merkle_value.poly_tx_hash);
+
+        Ok(to_merkle_value)
+    }
+
+    fn validate_inbound_tx(&self, to_merkle_value: &ToMerkleValue) -> SCResult<()> {
+        let tx = &to_merkle_value.tx;
+
+        require!(
+            tx.to_chain_id == self.own_chain_id().get(),
+            "Transaction is not meant for this chain"
+        );
+
+        require!(
+            tx.to_contract_address.len() == Address::len_bytes(),
+            "Invalid destination contract address"
+        );
+        let app_address = Address::from_slice(tx.to_contract_address.as_slice());
+        require!(
+            self.registered_applications().contains(&app_address),
+            "Destination contract is not a registered application"
+        );
+        require!(
+            self.is_application_method_allowed(&app_address, &tx.method_name),
+            "Method not allowed for the destination application"
+        );
+
+        let source_contract_mapper =
+            self.application_source_contract(&app_address, to_merkle_value.from_chain_id);
+        require!(
+            !source_contract_mapper.is_empty(),
+            "Source chain is not registered"
+        );
+        require!(
+            tx.from_contract_address == source_contract_mapper.get(),
+            "Source contract is not the registered proxy for the source chain"
+        );
+
+        Ok(())
+    }
+
+    /// Calls the destination application with the raw method args, the source contract and the source chain ID.
+    /// The whole call fails if the application's endpoint fails. Otherwise, the tx is marked as executed,
+    /// or as failed, if the application recorded it to be retried or refunded.
+    fn dispatch_tx(&self, to_merkle_value: ToMerkleValue) -> SCResult<()> {
+        self.receive_tx_event(&to_merkle_value.tx);
+
+        let tx = to_merkle_value.tx;
+        let app_address = Address::from_slice(tx.to_contract_address.as_slice());
+        let method_args = if self.is_legacy_transaction_relayer(&app_address) {
+            self.raw_args_to_legacy(&tx.method_args)?
+        } else {
+            tx.method_args
+        };
+
+        let mut serialized_from_chain_id = Vec::new();
+        let _ = to_merkle_value
+            .from_chain_id
+            .top_encode(&mut serialized_from_chain_id);
+
+        let mut arg_buffer = ArgBuffer::new();
+        arg_buffer.push_argument_bytes(method_args.as_slice());
+        arg_buffer.push_argument_bytes(tx.from_contract_address.as_slice());
+        arg_buffer.push_argument_bytes(serialized_from_chain_id.as_slice());
+
+        let results = self.send().execute_on_dest_context_raw(
+            self.blockchain().get_gas_left(),
+            &app_address,
+            &Self::BigUint::zero(),
+            tx.method_name.as_slice(),
+            &arg_buffer,
+        );
+
+        let status = self.get_dispatched_tx_status(&results);
+        if status == TransactionStatus::Failed {
+            self.record_failed_tx(
+                &app_address,
+                &results,
+                to_merkle_value.from_chain_id,
+                &to_merkle_value.poly_tx_hash,
+            );
+        }
+
+        self.change_tx_status(
+            to_merkle_value.from_chain_id,
+            &to_merkle_value.poly_tx_hash,
+            status,
+        )
+    }
+
+    fn migrate_storage(
+        &self,
+        transaction_relayer_chain_proxies: &[(u64, BoxedBytes)],
+    ) -> SCResult<()> {
+        let initial_version = self.storage_version().get();
+        require!(
+            initial_version <= CURRENT_STORAGE_VERSION,
+            "Storage version is newer than the contract code"
+        );
+
+        let mut version = initial_version;
+        while version < CURRENT_STORAGE_VERSION {
+            self.migrate_storage_from(version, transaction_relayer_chain_proxies)?;
+            version += 1;
+        }
+
+        if initial_version != CURRENT_STORAGE_VERSION {
+            self.storage_version().set(&CURRENT_STORAGE_VERSION);
+            self.storage_migrated_event(initial_version, CURRENT_STORAGE_VERSION);
+        }
+
+        Ok(())
+    }
+
+    /// Migrates the storage from the given version to the next one
+    fn migrate_storage_from(
+        &self,
+        version: u32,
+        transaction_relayer_chain_proxies: &[(u64, BoxedBytes)],
+    ) -> SCResult<()> {
+        match version {
+            // the baseline relayer was neither registered nor allowed to send, and it cannot be upgraded.
+            // It keeps working through the legacy args format, until it's retired and redeployed.
+            0 => {
+                if !self.transaction_relayer_contract_address().is_empty() {
+                    let tx_relayer_address = self.transaction_relayer_contract_address().get();
+                    self.add_application(
+                        &tx_relayer_address,
+                        &[transaction_relayer::UNLOCK_METHOD_NAME.into()],
+                    );
+                    self.allowed_senders().insert(tx_relayer_address.clone());
+
+                    for (chain_id, proxy_hash) in transaction_relayer_chain_proxies {
+                        self.application_source_contract(&tx_relayer_address, *chain_id)
+                            .set(proxy_hash);
+                    }
+
+                    self.legacy_transaction_relayer().set(&true);
+                }
+
+                Ok(())
+            }
+            // relayers deployed before NFT support only had the unlock method registered
+            1 => {
+                let tx_relayer_address = self.transaction_relayer_contract_address().get();
+                if self.is_upgradeable_transaction_relayer_registered(&tx_relayer_address) {
+                    self.add_application(
+                        &tx_relayer_address,
+                        &[transaction_relayer::UNLOCK_NFT_METHOD_NAME.into()],
+                    );
+                }
+
+                Ok(())
+            }
+            // inbound txs used to be checked against the relayer's own proxy hashes only,
+            // so the relayer has to be upgraded before this contract
+            2 => {
+                let tx_relayer_address = self.transaction_relayer_contract_address().get();
+                if self.is_upgradeable_transaction_relayer_registered(&tx_relayer_address) {
+                    let chain_proxies = self
+                        .transaction_relayer_proxy(tx_relayer_address.clone())
+                        .get_chain_proxies()
+                        .execute_on_dest_context();
+
+                    for chain_proxy in chain_proxies.into_vec() {
+                        let (chain_id, proxy_hash) = chain_proxy.into_tuple();
+                        self.application_source_contract(&tx_relayer_address, chain_id)
+                            .set(&proxy_hash);
+                    }
+                }
+
+                Ok(())
+            }
+            _ => sc_error!("No migration defined for storage version"),
+        }
+    }
+
+    /// The legacy relayer can neither unlock NFTs nor list its chain proxies
+    fn is_upgradeable_transaction_relayer_registered(&self, tx_relayer_address: &Address) -> bool {
+        self.registered_applications().contains(tx_relayer_address)
+            && !self.legacy_transaction_relayer().get()
+    }
+
+    fn is_legacy_transaction_relayer(&self, address: &Address) -> bool {
+        self.legacy_transaction_relayer().get()
+            && address == &self.transaction_relayer_contract_address().get()
+    }
+
+    /// The legacy relayer sends the TransactionArgs with their length prefix
+    fn legacy_args_to_raw(&self, legacy_args: &BoxedBytes) -> SCResult<BoxedBytes> {
+        let args = TransactionArgs::<Self::BigUint>::top_decode(legacy_args.as_slice())?;
+
+        match args.to_raw_bytes() {
+            Result::Ok(raw_args) => Ok(raw_args),
+            Result::Err(_) => sc_error!("Failed to serialize transaction args"),
+        }
+    }
+
+    /// The legacy relayer expects the TransactionArgs with their length prefix
+    fn raw_args_to_legacy(&self, raw_args: &BoxedBytes) -> SCResult<BoxedBytes> {
+        let args = TransactionArgs::<Self::BigUint>::from_raw_bytes(raw_args.as_slice())?;
+
+        let mut legacy_args = Vec::new();
+        match args.top_encode(&mut legacy_args) {
+            Result::Ok(()) => Ok(legacy_args.as_slice().into()),
+            Result::Err(_) => sc_error!("Failed to serialize transaction args"),
+        }
+    }
+
+    fn call_transaction_relayer_pause_endpoint(
+        &self,
+        endpoint_name: &[u8],
+        inbound: bool,
+        outbound: bool,
+    ) -> SCResult<()> {
+        let mut arg_buffer = ArgBuffer::new();
+        push_top_encoded_arg(&mut arg_buffer, &inbound);
+        push_top_encoded_arg(&mut arg_buffer, &outbound);
+
+        self.call_transaction_relayer_module_endpoint(endpoint_name, &arg_buffer)
+    }
+
+    /// Endpoints of the relayer's modules are called by name, as they're not part of its proxy
+    fn call_transaction_relayer_module_endpoint(
+        &self,
+        endpoint_name: &[u8],
+        arg_buffer: &ArgBuffer,
+    ) -> SCResult<()> {
+        self.require_transaction_relayer_deployed()?;
+
+        let _ = self.send().execute_on_dest_context_raw(
+            self.blockchain().get_gas_left(),
+            &self.transaction_relayer_contract_address().get(),
+            &Self::BigUint::zero(),
+            endpoint_name,
+            arg_buffer,
+        );
+
+        Ok(())
+    }
+
     // proxies
 
     #[proxy]
@@ -274,24 +1216,99 @@ pub trait CrossChainManagement {
         sc_address: Address,
     ) -> transaction_relayer::Proxy<Self::SendApi>;
 
+    #[proxy]
+    fn change_owner_proxy(&self, sc_address: Address) -> change_owner_proxy::Proxy<Self::SendApi>;
+
+    // callbacks
+
+    #[callback]
+    fn change_owner_callback(
+        &self,
+        tx_relayer_address: &Address,
+        #[call_result] result: AsyncCallResult<()>,
+    ) {
+        match result {
+            AsyncCallResult::Ok(()) => {
+                if &self.transaction_relayer_contract_address().get() == tx_relayer_address {
+                    self.transaction_relayer_contract_address().clear();
+                    self.legacy_transaction_relayer().clear();
+                }
+                self.allowed_senders().remove(tx_relayer_address);
+                self.remove_application(tx_relayer_address);
+
+                self.transaction_relayer_ownership_transferred_event(tx_relayer_address);
+            }
+            AsyncCallResult::Err(err) => {
+                self.transaction_relayer_ownership_transfer_failed_event(
+                    tx_relayer_address,
+                    &err.err_msg,
+                );
+            }
+        }
+    }
+
     // events
 
     // for tx from Elrond to another chain
     #[event("createTransaction")]
-    fn create_tx_event(&self, tx: &Transaction<Self::BigUint>);
+    fn create_tx_event(&self, tx: &Transaction);
 
     // for tx from another chain to Elrond
     #[event("receiveTransaction")]
-    fn receive_tx_event(&self, tx: &Transaction<Self::BigUint>);
+    fn receive_tx_event(&self, tx: &Transaction);
+
+    #[event("batchTxExecuted")]
+    fn batch_tx_executed_event(
+        &self,
+        #[indexed] index: usize,
+        #[indexed] from_chain_id: u64,
+        poly_tx_hash: &H256,
+    );
+
+    #[event("batchTxFailed")]
+    fn batch_tx_failed_event(&self, #[indexed] index: usize, reason: &BoxedBytes);
+
+    #[event("headerVerified")]
+    fn header_verified_event(&self, #[indexed] height: u32, cross_state_root: &H256);
+
+    #[event("transactionRelayerOwnershipTransferred")]
+    fn transaction_relayer_ownership_transferred_event(
+        &self,
+        #[indexed] tx_relayer_address: &Address,
+    );
+
+    #[event("transactionRelayerOwnershipTransferFailed")]
+    fn transaction_relayer_ownership_transfer_failed_event(
+        &self,
+        #[indexed] tx_relayer_address: &Address,
+        reason: &BoxedBytes,
+    );
+
+    #[event("legacyTransactionRelayerRetired")]
+    fn legacy_transaction_relayer_retired_event(&self, #[indexed] tx_relayer_address: &Address);
+
+    #[event("storageMigrated")]
+    fn storage_migrated_event(&self, #[indexed] from_version: u32, to_version: u32);
 
     // storage
 
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<Self::Storage, u32>;
+
+    #[view(getHeaderSyncContractAddress)]
     #[storage_mapper("headerSyncContractAddress")]
     fn header_sync_contract_address(&self) -> SingleValueMapper<Self::Storage, Address>;
 
+    #[view(getTransactionRelayerContractAddress)]
     #[storage_mapper("transactionRelayerContractAddress")]
     fn transaction_relayer_contract_address(&self) -> SingleValueMapper<Self::Storage, Address>;
 
+    /// Set for the relayer deployed by the baseline contract, which uses the legacy args format
+    #[view(isTransactionRelayerLegacy)]
+    #[storage_mapper("legacyTransactionRelayer")]
+    fn legacy_transaction_relayer(&self) -> SingleValueMapper<Self::Storage, bool>;
+
     #[view(getOwnChainId)]
     #[storage_mapper("ownChainId")]
     fn own_chain_id(&self) -> SingleValueMapper<Self::Storage, u64>;
@@ -300,6 +1317,23 @@ pub trait CrossChainManagement {
     #[storage_mapper("crossChainTxId")]
     fn cross_chain_tx_id(&self) -> SingleValueMapper<Self::Storage, u64>;
 
+    /// Cross state roots of Poly headers that were already verified, by header height
+    #[view(getVerifiedCrossStateRoot)]
+    #[storage_mapper("verifiedCrossStateRoot")]
+    fn verified_cross_state_root(
+        &self,
+        header_height: u32,
+    ) -> SingleValueMapper<Self::Storage, H256>;
+
+    #[storage_mapper("outboundTx")]
+    fn outbound_tx(&self, cross_chain_tx_id: u64) -> SingleValueMapper<Self::Storage, Transaction>;
+
+    #[storage_mapper("outboundTxIdByHash")]
+    fn outbound_tx_id_by_hash(&self, tx_hash: &H256) -> SingleValueMapper<Self::Storage, u64>;
+
+    #[storage_mapper("outboundTxIdsByChain")]
+    fn outbound_tx_ids_by_chain(&self, to_chain_id: u64) -> VecMapper<Self::Storage, u64>;
+
     #[storage_mapper("txExists")]
     fn tx_exists(
         &self,
@@ -307,3 +1341,10 @@ pub trait CrossChainManagement {
         poly_tx_hash: &H256,
     ) -> SingleValueMapper<Self::Storage, bool>;
 }
+
+fn push_top_encoded_arg<T: TopEncode>(arg_buffer: &mut ArgBuffer, arg: &T) {
+    let mut serialized = Vec::new();
+    let _ = arg.top_encode(&mut serialized);
+
+    arg_buffer.push_argument_bytes(serialized.as_slice());
+}
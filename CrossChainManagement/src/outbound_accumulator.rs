@@ -0,0 +1,82 @@
+use merkle_proof::mmr::MerkleMountainRange;
+
+elrond_wasm::imports!();
+
+/// Merkle Mountain Range over the hashes of all outbound transactions, in creation order.
+/// Leaves are the raw 32-byte tx hashes. Proofs are generated off-chain
+/// (see `merkle_proof::builder::MerkleTreeBuilder`) and checked against getOutboundTxRoot.
+#[elrond_wasm_derive::module]
+pub trait OutboundAccumulatorModule {
+    // views
+
+    #[view(getOutboundTxRoot)]
+    fn get_outbound_tx_root(&self) -> H256 {
+        self.outbound_tx_root().get()
+    }
+
+    #[view(getOutboundTxAccumulatorSize)]
+    fn get_outbound_tx_accumulator_size(&self) -> u64 {
+        self.outbound_tx_accumulator_size().get()
+    }
+
+    #[view(getOutboundTxAccumulatorPeaks)]
+    fn get_outbound_tx_accumulator_peaks(&self) -> MultiResultVec<H256> {
+        self.outbound_tx_accumulator_peaks().get().into()
+    }
+
+    /// Index of the tx's leaf in the accumulator
+    #[view(getOutboundTxLeafIndex)]
+    fn get_outbound_tx_leaf_index(&self, tx_hash: H256) -> SCResult<u64> {
+        let leaf_index_mapper = self.outbound_tx_leaf_index(&tx_hash);
+        require!(
+            !leaf_index_mapper.is_empty(),
+            "Transaction not found in accumulator"
+        );
+
+        Ok(leaf_index_mapper.get())
+    }
+
+    // private
+
+    fn accumulate_outbound_tx(&self, tx_hash: &H256) -> SCResult<()> {
+        let mut mmr = match MerkleMountainRange::from_peaks(
+            self.crypto(),
+            self.outbound_tx_accumulator_peaks().get(),
+            self.outbound_tx_accumulator_size().get(),
+        ) {
+            Some(mmr) => mmr,
+            None => return sc_error!("Outbound tx accumulator is corrupted"),
+        };
+
+        let leaf_index = mmr.append(tx_hash.as_bytes());
+        let root = mmr.root();
+
+        self.outbound_tx_leaf_index(tx_hash).set(&leaf_index);
+        self.outbound_tx_root().set(&root);
+        self.outbound_tx_accumulator_size().set(&mmr.size());
+        self.outbound_tx_accumulator_peaks().set(&mmr.into_peaks());
+
+        self.outbound_tx_accumulated_event(leaf_index, &root);
+
+        Ok(())
+    }
+
+    // events
+
+    #[event("outboundTxAccumulated")]
+    fn outbound_tx_accumulated_event(&self, #[indexed] leaf_index: u64, root: &H256);
+
+    // storage
+
+    #[storage_mapper("outboundTxRoot")]
+    fn outbound_tx_root(&self) -> SingleValueMapper<Self::Storage, H256>;
+
+    #[storage_mapper("outboundTxAccumulatorSize")]
+    fn outbound_tx_accumulator_size(&self) -> SingleValueMapper<Self::Storage, u64>;
+
+    #[storage_mapper("outboundTxAccumulatorPeaks")]
+    fn outbound_tx_accumulator_peaks(&self) -> SingleValueMapper<Self::Storage, Vec<H256>>;
+
+    #[storage_mapper("outboundTxLeafIndex")]
+    fn outbound_tx_leaf_index(&self, tx_hash: &H256) -> SingleValueMapper<Self::Storage, u64>;
+}
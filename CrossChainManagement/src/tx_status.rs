@@ -0,0 +1,116 @@
+use elrond_wasm::elrond_codec::TopDecode;
+use transaction::TransactionStatus;
+
+elrond_wasm::imports!();
+
+/// Status of each cross-chain transaction, by (source chain ID, tx hash).
+/// Outbound txs are keyed by Elrond's chain ID and their source_chain_tx_hash,
+/// inbound txs by their source chain ID and the Poly tx hash.
+#[elrond_wasm_derive::module]
+pub trait TxStatusModule {
+    // endpoints
+
+    /// Called by applications which executed a tx they had previously returned as Failed, e.g. on a retry.
+    /// Failures recorded before IDs were returned cannot be tracked, so unknown IDs are ignored.
+    #[endpoint(reportFailedTxExecuted)]
+    fn report_failed_tx_executed(&self, failed_tx_id: u64) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        let failed_tx_mapper = self.failed_tx(&caller, failed_tx_id);
+        if failed_tx_mapper.is_empty() {
+            return Ok(());
+        }
+
+        let (chain_id, tx_hash) = failed_tx_mapper.get();
+        failed_tx_mapper.clear();
+
+        self.change_tx_status(chain_id, &tx_hash, TransactionStatus::Executed)
+    }
+
+    // views
+
+    /// Returns None for unknown transactions
+    #[view(getTxStatus)]
+    fn get_tx_status(&self, chain_id: u64, tx_hash: H256) -> TransactionStatus {
+        self.tx_status(chain_id, &tx_hash).get()
+    }
+
+    // private
+
+    /// Applications may return Failed if they recorded the tx to be retried or refunded.
+    /// Any other result means the tx was executed.
+    fn get_dispatched_tx_status(&self, results: &[BoxedBytes]) -> TransactionStatus {
+        match results
+            .first()
+            .map(|result| TransactionStatus::top_decode(result.as_slice()))
+        {
+            Some(Result::Ok(TransactionStatus::Failed)) => TransactionStatus::Failed,
+            _ => TransactionStatus::Executed,
+        }
+    }
+
+    /// Applications returning Failed may also return the ID under which they recorded the tx,
+    /// so they can later report it as executed.
+    fn record_failed_tx(
+        &self,
+        app_address: &Address,
+        results: &[BoxedBytes],
+        chain_id: u64,
+        tx_hash: &H256,
+    ) {
+        let failed_tx_id = match results
+            .get(1)
+            .map(|result| u64::top_decode(result.as_slice()))
+        {
+            Some(Result::Ok(failed_tx_id)) if failed_tx_id > 0 => failed_tx_id,
+            _ => return,
+        };
+
+        self.failed_tx(app_address, failed_tx_id)
+            .set(&(chain_id, tx_hash.clone()));
+    }
+
+    fn change_tx_status(
+        &self,
+        chain_id: u64,
+        tx_hash: &H256,
+        new_status: TransactionStatus,
+    ) -> SCResult<()> {
+        let status_mapper = self.tx_status(chain_id, tx_hash);
+        require!(
+            status_mapper.get().can_transition_to(new_status),
+            "Invalid transaction status transition"
+        );
+
+        status_mapper.set(&new_status);
+        self.tx_status_changed_event(chain_id, tx_hash, new_status);
+
+        Ok(())
+    }
+
+    // events
+
+    #[event("txStatusChanged")]
+    fn tx_status_changed_event(
+        &self,
+        #[indexed] chain_id: u64,
+        #[indexed] tx_hash: &H256,
+        new_status: TransactionStatus,
+    );
+
+    // storage
+
+    #[storage_mapper("txStatus")]
+    fn tx_status(
+        &self,
+        chain_id: u64,
+        tx_hash: &H256,
+    ) -> SingleValueMapper<Self::Storage, TransactionStatus>;
+
+    /// The (chain ID, tx hash) of txs which failed, by application and the ID the application returned
+    #[storage_mapper("failedTx")]
+    fn failed_tx(
+        &self,
+        app_address: &Address,
+        failed_tx_id: u64,
+    ) -> SingleValueMapper<Self::Storage, (u64, H256)>;
+}
@@ -0,0 +1,162 @@
+use cross_chain_management::app_registry::AppRegistryModule;
+use cross_chain_management::*;
+use elrond_wasm::elrond_codec::TopEncode;
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, VarArgs, H256};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+
+use merkle_proof::builder::MerkleTreeBuilder;
+use transaction::*;
+
+const OWN_CHAIN_ID: u64 = 42;
+const SOURCE_CHAIN_ID: u64 = 5;
+const HEADER_HEIGHT: u32 = 1_000;
+
+const RELAYER_ADDRESS: [u8; 32] = [7u8; 32];
+const SOURCE_PROXY: &[u8] = b"tx_relayer_addr_other_chain";
+
+fn setup() -> impl CrossChainManagement {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let relayer_address = Address::from(RELAYER_ADDRESS);
+
+    ccm.own_chain_id().set(&OWN_CHAIN_ID);
+    ccm.transaction_relayer_contract_address()
+        .set(&relayer_address);
+    ccm.registered_applications()
+        .insert(relayer_address.clone());
+    ccm.application_methods(&relayer_address)
+        .insert(BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME));
+    ccm.application_source_contract(&relayer_address, SOURCE_CHAIN_ID)
+        .set(&BoxedBytes::from(SOURCE_PROXY));
+
+    ccm
+}
+
+fn to_merkle_value(poly_tx_hash: u8) -> ToMerkleValue {
+    ToMerkleValue {
+        poly_tx_hash: H256::from([poly_tx_hash; 32]),
+        from_chain_id: SOURCE_CHAIN_ID,
+        tx: Transaction {
+            source_chain_tx_hash: H256::from([2u8; 32]),
+            cross_chain_tx_id: BoxedBytes::from(&[poly_tx_hash][..]),
+            from_contract_address: BoxedBytes::from(SOURCE_PROXY),
+            to_chain_id: OWN_CHAIN_ID,
+            to_contract_address: BoxedBytes::from(&RELAYER_ADDRESS[..]),
+            method_name: BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME),
+            method_args: TransactionArgs::<RustBigUint> {
+                asset_hash: BoxedBytes::from(&b"WEGLD-123456"[..]),
+                dest_address: BoxedBytes::from(&[3u8; 32][..]),
+                amount: RustBigUint::from(1_000u64),
+                from_address: BoxedBytes::empty(),
+                data: BoxedBytes::empty(),
+            }
+            .to_raw_bytes()
+            .unwrap(),
+        },
+    }
+}
+
+/// Stores the cross state root of a tree containing the given txs, and returns their proofs
+fn prove_txs(
+    ccm: &impl CrossChainManagement,
+    to_merkle_values: &[ToMerkleValue],
+) -> Vec<BoxedBytes> {
+    let mut builder = MerkleTreeBuilder::new(TxContext::dummy());
+    for to_merkle_value in to_merkle_values {
+        let mut raw_tx = Vec::new();
+        let _ = to_merkle_value.top_encode(&mut raw_tx);
+        builder.add_leaf(&raw_tx);
+    }
+
+    ccm.verified_cross_state_root(HEADER_HEIGHT)
+        .set(&builder.root());
+
+    (0..to_merkle_values.len())
+        .map(|index| builder.serialized_proof(index).unwrap())
+        .collect()
+}
+
+fn prepare_tx_batch(
+    ccm: &impl CrossChainManagement,
+    tx_proofs: &[BoxedBytes],
+) -> Vec<ToMerkleValue> {
+    let cross_state_root = ccm.verified_cross_state_root(HEADER_HEIGHT).get();
+
+    ccm.prepare_tx_batch(tx_proofs, &cross_state_root)
+}
+
+#[test]
+fn partial_failure_test() {
+    let ccm = setup();
+    let mut wrong_method_tx = to_merkle_value(2);
+    wrong_method_tx.tx.method_name = BoxedBytes::from(&b"mint"[..]);
+    let tx_proofs = prove_txs(
+        &ccm,
+        &[to_merkle_value(1), wrong_method_tx, to_merkle_value(3)],
+    );
+
+    // the invalid tx is skipped, the others are still executed
+    let valid_txs = prepare_tx_batch(&ccm, &tx_proofs);
+    let valid_tx_hashes: Vec<H256> = valid_txs.iter().map(|tx| tx.poly_tx_hash.clone()).collect();
+    assert_eq!(
+        valid_tx_hashes,
+        vec![H256::from([1u8; 32]), H256::from([3u8; 32])]
+    );
+
+    assert!(ccm.does_tx_exist(SOURCE_CHAIN_ID, &H256::from([1u8; 32])));
+    assert!(!ccm.does_tx_exist(SOURCE_CHAIN_ID, &H256::from([2u8; 32])));
+    assert!(ccm.does_tx_exist(SOURCE_CHAIN_ID, &H256::from([3u8; 32])));
+}
+
+#[test]
+fn duplicate_tx_in_batch_test() {
+    let ccm = setup();
+    let tx_proofs = prove_txs(&ccm, &[to_merkle_value(1), to_merkle_value(2)]);
+    let tx_proofs = vec![tx_proofs[0].clone(), tx_proofs[0].clone()];
+
+    let valid_txs = prepare_tx_batch(&ccm, &tx_proofs);
+    assert_eq!(valid_txs.len(), 1);
+}
+
+#[test]
+fn no_valid_tx_test() {
+    let ccm = setup();
+    let mut wrong_chain_tx = to_merkle_value(1);
+    wrong_chain_tx.tx.to_chain_id = OWN_CHAIN_ID + 1;
+    let mut wrong_source_tx = to_merkle_value(2);
+    wrong_source_tx.tx.from_contract_address = BoxedBytes::from(&b"some_other_contract"[..]);
+    let tx_proofs = prove_txs(&ccm, &[wrong_chain_tx, wrong_source_tx]);
+
+    // nothing is dispatched, and the call itself succeeds
+    assert!(ccm
+        .execute_tx_batch_with_known_header(HEADER_HEIGHT, VarArgs::from(tx_proofs))
+        .is_ok());
+    assert!(!ccm.does_tx_exist(SOURCE_CHAIN_ID, &H256::from([1u8; 32])));
+    assert!(!ccm.does_tx_exist(SOURCE_CHAIN_ID, &H256::from([2u8; 32])));
+}
+
+#[test]
+fn dispatch_failure_reverts_batch_test() {
+    let ccm = setup();
+    // the legacy relayer's args are converted before the call, which fails for malformed args
+    ccm.legacy_transaction_relayer().set(&true);
+    let mut malformed_args_tx = to_merkle_value(2);
+    malformed_args_tx.tx.method_args = BoxedBytes::from(&b"malformed"[..]);
+    let mut wrong_chain_tx = to_merkle_value(1);
+    wrong_chain_tx.tx.to_chain_id = OWN_CHAIN_ID + 1;
+    let tx_proofs = prove_txs(&ccm, &[wrong_chain_tx, malformed_args_tx]);
+
+    match ccm.execute_tx_batch_with_known_header(HEADER_HEIGHT, VarArgs::from(tx_proofs)) {
+        SCResult::Ok(_) => panic!("Batch should have failed"),
+        SCResult::Err(_) => {}
+    }
+}
+
+#[test]
+fn empty_batch_test() {
+    let ccm = setup();
+
+    match ccm.execute_tx_batch_with_known_header(HEADER_HEIGHT, VarArgs::from(Vec::new())) {
+        SCResult::Ok(_) => panic!("Batch should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), &b"No tx proofs provided"[..]),
+    }
+}
@@ -0,0 +1,189 @@
+use cross_chain_management::*;
+use elrond_wasm::api::CryptoApi;
+use elrond_wasm::elrond_codec::{TopDecode, TopEncode};
+use elrond_wasm::types::{BoxedBytes, SCResult, H256};
+use elrond_wasm_debug::TxContext;
+
+use eth_address::EthAddress;
+use header::Header;
+use merkle_proof::builder::MerkleTreeBuilder;
+
+const HEADER_HEIGHT: u32 = 1_000;
+
+fn raw_header(height: u32, cross_state_root: H256) -> BoxedBytes {
+    raw_header_with_block_root(height, cross_state_root, H256::zero())
+}
+
+fn raw_header_with_block_root(height: u32, cross_state_root: H256, block_root: H256) -> BoxedBytes {
+    let header = Header {
+        version: 0,
+        chain_id: 0,
+        prev_block_hash: H256::zero(),
+        transactions_root: H256::zero(),
+        cross_state_root,
+        block_root,
+        timestamp: 0,
+        height,
+        consensus_data: 0,
+        consensus_payload: BoxedBytes::empty(),
+        next_book_keeper: EthAddress::zero(),
+    };
+
+    let mut serialized = Vec::new();
+    let _ = header.top_encode(&mut serialized);
+
+    serialized.into()
+}
+
+fn header_hash(raw_header: &BoxedBytes) -> H256 {
+    TxContext::dummy().sha256(raw_header.as_slice())
+}
+
+/// Block root tree of a current header, with the given tx header hash as second leaf
+fn block_root_tree(tx_header_hash: &H256) -> MerkleTreeBuilder<TxContext> {
+    let mut builder = MerkleTreeBuilder::new(TxContext::dummy());
+    builder.add_leaf(H256::from([9u8; 32]).as_bytes());
+    builder.add_leaf(tx_header_hash.as_bytes());
+
+    builder
+}
+
+#[test]
+fn header_proven_by_block_root_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let raw_tx_header = raw_header(HEADER_HEIGHT, H256::from([1u8; 32]));
+    let builder = block_root_tree(&header_hash(&raw_tx_header));
+
+    let result = ccm.require_header_proven(
+        &builder.serialized_proof(1).unwrap(),
+        &builder.root(),
+        &raw_tx_header,
+    );
+    assert!(result.is_ok());
+}
+
+/// The proven leaf used to be compared against the current header's hash,
+/// which rejected every valid proof of an older tx header
+#[test]
+fn proven_leaf_is_tx_header_hash_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let raw_tx_header = raw_header(HEADER_HEIGHT, H256::from([1u8; 32]));
+    let builder = block_root_tree(&header_hash(&raw_tx_header));
+    let raw_current_header =
+        raw_header_with_block_root(HEADER_HEIGHT + 10, H256::from([2u8; 32]), builder.root());
+    let current_header = Header::top_decode(raw_current_header.as_slice()).unwrap();
+    assert!(header_hash(&raw_current_header) != header_hash(&raw_tx_header));
+
+    assert!(ccm
+        .require_header_proven(
+            &builder.serialized_proof(1).unwrap(),
+            &current_header.block_root,
+            &raw_tx_header,
+        )
+        .is_ok());
+
+    // a proof is only valid for the header it proves
+    match ccm.require_header_proven(
+        &builder.serialized_proof(1).unwrap(),
+        &current_header.block_root,
+        &raw_current_header,
+    ) {
+        SCResult::Ok(_) => panic!("Proof for the tx header should not prove the current header"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Current header merkle proof failed: hash does not match proven value"[..]
+        ),
+    }
+}
+
+#[test]
+fn proof_for_wrong_header_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let raw_tx_header = raw_header(HEADER_HEIGHT, H256::from([1u8; 32]));
+    let builder = block_root_tree(&header_hash(&raw_tx_header));
+
+    // valid proof against the block root, but for another header
+    match ccm.require_header_proven(
+        &builder.serialized_proof(0).unwrap(),
+        &builder.root(),
+        &raw_tx_header,
+    ) {
+        SCResult::Ok(_) => panic!("Proof for another header should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Current header merkle proof failed: hash does not match proven value"[..]
+        ),
+    }
+}
+
+#[test]
+fn proof_for_wrong_block_root_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let raw_tx_header = raw_header(HEADER_HEIGHT, H256::from([1u8; 32]));
+    let builder = block_root_tree(&header_hash(&raw_tx_header));
+
+    match ccm.require_header_proven(
+        &builder.serialized_proof(1).unwrap(),
+        &H256::zero(),
+        &raw_tx_header,
+    ) {
+        SCResult::Ok(_) => panic!("Proof against another block root should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Current header merkle proof failed: Block Root does not match"[..]
+        ),
+    }
+}
+
+#[test]
+fn known_header_is_not_verified_again_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let cross_state_root = H256::from([1u8; 32]);
+    ccm.verified_cross_state_root(HEADER_HEIGHT)
+        .set(&cross_state_root);
+
+    // no header sync is set, so this only passes if the cached root is used
+    match ccm.verify_tx_header(
+        &raw_header(HEADER_HEIGHT, cross_state_root.clone()),
+        &BoxedBytes::empty(),
+        &BoxedBytes::empty(),
+        Vec::new(),
+    ) {
+        SCResult::Ok(root) => assert_eq!(root, cross_state_root),
+        SCResult::Err(_) => panic!("Known header should have been accepted"),
+    }
+}
+
+#[test]
+fn known_height_with_other_root_is_verified_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    ccm.verified_cross_state_root(HEADER_HEIGHT)
+        .set(&H256::from([1u8; 32]));
+
+    // a different header at a known height goes through the full verification,
+    // which fails early here, as the current header cannot be decoded
+    let result = ccm.verify_tx_header(
+        &raw_header(HEADER_HEIGHT, H256::from([2u8; 32])),
+        &BoxedBytes::empty(),
+        &BoxedBytes::empty(),
+        Vec::new(),
+    );
+    assert!(result.is_err());
+    assert_eq!(
+        ccm.verified_cross_state_root(HEADER_HEIGHT).get(),
+        H256::from([1u8; 32])
+    );
+}
+
+#[test]
+fn unknown_header_height_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+
+    match ccm.execute_tx_with_known_header(BoxedBytes::empty(), HEADER_HEIGHT) {
+        SCResult::Ok(_) => panic!("Unknown header height should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"No verified header known for the given height"[..]
+        ),
+    }
+}
@@ -0,0 +1,268 @@
+use cross_chain_management::app_registry::AppRegistryModule;
+use cross_chain_management::*;
+use elrond_wasm::elrond_codec::TopEncode;
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, H256};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use pause::PauseModule;
+
+use merkle_proof::builder::MerkleTreeBuilder;
+use transaction::*;
+
+const OWN_CHAIN_ID: u64 = 42;
+const SOURCE_CHAIN_ID: u64 = 5;
+const HEADER_HEIGHT: u32 = 1_000;
+
+const RELAYER_ADDRESS: [u8; 32] = [7u8; 32];
+const SOURCE_PROXY: &[u8] = b"tx_relayer_addr_other_chain";
+
+const NFT_BRIDGE_ADDRESS: [u8; 32] = [9u8; 32];
+const NFT_BRIDGE_SOURCE: &[u8] = b"nft_bridge_addr_other_chain";
+const NFT_BRIDGE_METHOD_NAME: &[u8] = b"receiveNft";
+
+fn setup() -> impl CrossChainManagement {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let relayer_address = Address::from(RELAYER_ADDRESS);
+
+    ccm.own_chain_id().set(&OWN_CHAIN_ID);
+    ccm.transaction_relayer_contract_address()
+        .set(&relayer_address);
+    ccm.registered_applications()
+        .insert(relayer_address.clone());
+    ccm.application_methods(&relayer_address)
+        .insert(BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME));
+    ccm.application_source_contract(&relayer_address, SOURCE_CHAIN_ID)
+        .set(&BoxedBytes::from(SOURCE_PROXY));
+
+    ccm
+}
+
+/// A second application, registered next to the relayer, with its own method and source contract
+fn setup_with_nft_bridge() -> impl CrossChainManagement {
+    let ccm = setup();
+    let nft_bridge_address = Address::from(NFT_BRIDGE_ADDRESS);
+
+    ccm.add_application(
+        &nft_bridge_address,
+        &[BoxedBytes::from(NFT_BRIDGE_METHOD_NAME)],
+    );
+    ccm.application_source_contract(&nft_bridge_address, SOURCE_CHAIN_ID)
+        .set(&BoxedBytes::from(NFT_BRIDGE_SOURCE));
+
+    ccm
+}
+
+fn nft_bridge_to_merkle_value() -> ToMerkleValue {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.from_contract_address = BoxedBytes::from(NFT_BRIDGE_SOURCE);
+    to_merkle_value.tx.to_contract_address = BoxedBytes::from(&NFT_BRIDGE_ADDRESS[..]);
+    to_merkle_value.tx.method_name = BoxedBytes::from(NFT_BRIDGE_METHOD_NAME);
+
+    to_merkle_value
+}
+
+fn assert_second_app_rejected(to_merkle_value: ToMerkleValue, expected_err: &[u8]) {
+    let ccm = setup_with_nft_bridge();
+
+    match ccm.validate_inbound_tx(&to_merkle_value) {
+        SCResult::Ok(_) => panic!("Tx should have been rejected"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn valid_to_merkle_value() -> ToMerkleValue {
+    ToMerkleValue {
+        poly_tx_hash: H256::from([1u8; 32]),
+        from_chain_id: SOURCE_CHAIN_ID,
+        tx: Transaction {
+            source_chain_tx_hash: H256::from([2u8; 32]),
+            cross_chain_tx_id: BoxedBytes::from(&[0u8][..]),
+            from_contract_address: BoxedBytes::from(SOURCE_PROXY),
+            to_chain_id: OWN_CHAIN_ID,
+            to_contract_address: BoxedBytes::from(&RELAYER_ADDRESS[..]),
+            method_name: BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME),
+            method_args: TransactionArgs::<RustBigUint> {
+                asset_hash: BoxedBytes::from(&b"WEGLD-123456"[..]),
+                dest_address: BoxedBytes::from(&[3u8; 32][..]),
+                amount: RustBigUint::from(1_000u64),
+                from_address: BoxedBytes::empty(),
+                data: BoxedBytes::empty(),
+            }
+            .to_raw_bytes()
+            .unwrap(),
+        },
+    }
+}
+
+/// Stores the cross state root of a tree containing only the given tx, and returns the tx proof
+fn prove_tx(ccm: &impl CrossChainManagement, to_merkle_value: &ToMerkleValue) -> BoxedBytes {
+    let mut raw_tx = Vec::new();
+    let _ = to_merkle_value.top_encode(&mut raw_tx);
+
+    let mut builder = MerkleTreeBuilder::new(TxContext::dummy());
+    builder.add_leaf(b"some_other_tx");
+    builder.add_leaf(&raw_tx);
+
+    ccm.verified_cross_state_root(HEADER_HEIGHT)
+        .set(&builder.root());
+
+    builder.serialized_proof(1).unwrap()
+}
+
+fn assert_rejected(to_merkle_value: ToMerkleValue, expected_err: &str) {
+    let ccm = setup();
+    let tx_proof = prove_tx(&ccm, &to_merkle_value);
+
+    match ccm.execute_tx_with_known_header(tx_proof, HEADER_HEIGHT) {
+        SCResult::Ok(_) => panic!("Tx should have been rejected: {}", expected_err),
+        SCResult::Err(err) => {
+            assert_eq!(std::str::from_utf8(err.as_bytes()).unwrap(), expected_err)
+        }
+    }
+}
+
+#[test]
+fn wrong_destination_chain_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.to_chain_id = OWN_CHAIN_ID + 1;
+
+    assert_rejected(to_merkle_value, "Transaction is not meant for this chain");
+}
+
+#[test]
+fn wrong_destination_contract_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.to_contract_address = BoxedBytes::from(&[8u8; 32][..]);
+
+    assert_rejected(
+        to_merkle_value,
+        "Destination contract is not a registered application",
+    );
+}
+
+#[test]
+fn wrong_method_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.method_name = BoxedBytes::from(&b"mint"[..]);
+
+    assert_rejected(
+        to_merkle_value,
+        "Method not allowed for the destination application",
+    );
+}
+
+#[test]
+fn unregistered_source_chain_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.from_chain_id = SOURCE_CHAIN_ID + 1;
+
+    assert_rejected(to_merkle_value, "Source chain is not registered");
+}
+
+#[test]
+fn wrong_source_contract_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.from_contract_address = BoxedBytes::from(&b"some_other_contract"[..]);
+
+    assert_rejected(
+        to_merkle_value,
+        "Source contract is not the registered proxy for the source chain",
+    );
+}
+
+#[test]
+fn wrong_cross_state_root_test() {
+    let ccm = setup();
+    let tx_proof = prove_tx(&ccm, &valid_to_merkle_value());
+    ccm.verified_cross_state_root(HEADER_HEIGHT)
+        .set(&H256::zero());
+
+    match ccm.execute_tx_with_known_header(tx_proof, HEADER_HEIGHT) {
+        SCResult::Ok(_) => panic!("Tx should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Tx merkle proof failed: Cross State Root does not match"[..]
+        ),
+    }
+}
+
+#[test]
+fn inbound_paused_test() {
+    let ccm = setup();
+    let tx_proof = prove_tx(&ccm, &valid_to_merkle_value());
+    ccm.inbound_paused().set(&true);
+
+    match ccm.execute_tx_with_known_header(tx_proof, HEADER_HEIGHT) {
+        SCResult::Ok(_) => panic!("Tx should have been rejected"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), &b"Inbound transfers are paused"[..]),
+    }
+}
+
+#[test]
+fn second_app_valid_tx_test() {
+    let ccm = setup_with_nft_bridge();
+
+    assert!(ccm
+        .validate_inbound_tx(&nft_bridge_to_merkle_value())
+        .is_ok());
+    // the relayer's txs are still routed to the relayer
+    assert!(ccm.validate_inbound_tx(&valid_to_merkle_value()).is_ok());
+}
+
+#[test]
+fn second_app_wrong_method_test() {
+    // the relayer's method is not allowed for the second application
+    let mut to_merkle_value = nft_bridge_to_merkle_value();
+    to_merkle_value.tx.method_name = BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME);
+
+    assert_second_app_rejected(
+        to_merkle_value,
+        b"Method not allowed for the destination application",
+    );
+}
+
+#[test]
+fn second_app_method_for_relayer_test() {
+    let mut to_merkle_value = valid_to_merkle_value();
+    to_merkle_value.tx.method_name = BoxedBytes::from(NFT_BRIDGE_METHOD_NAME);
+
+    assert_second_app_rejected(
+        to_merkle_value,
+        b"Method not allowed for the destination application",
+    );
+}
+
+#[test]
+fn second_app_wrong_source_contract_test() {
+    // each application only accepts txs from its own source contract
+    let mut to_merkle_value = nft_bridge_to_merkle_value();
+    to_merkle_value.tx.from_contract_address = BoxedBytes::from(SOURCE_PROXY);
+
+    assert_second_app_rejected(
+        to_merkle_value,
+        b"Source contract is not the registered proxy for the source chain",
+    );
+}
+
+#[test]
+fn second_app_unregistered_source_chain_test() {
+    let mut to_merkle_value = nft_bridge_to_merkle_value();
+    to_merkle_value.from_chain_id = SOURCE_CHAIN_ID + 1;
+
+    assert_second_app_rejected(to_merkle_value, b"Source chain is not registered");
+}
+
+#[test]
+fn unregistered_second_app_test() {
+    let ccm = setup_with_nft_bridge();
+    assert!(ccm.remove_application(&Address::from(NFT_BRIDGE_ADDRESS)));
+
+    match ccm.validate_inbound_tx(&nft_bridge_to_merkle_value()) {
+        SCResult::Ok(_) => panic!("Tx should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Destination contract is not a registered application"[..]
+        ),
+    }
+    // the relayer is not affected
+    assert!(ccm.validate_inbound_tx(&valid_to_merkle_value()).is_ok());
+}
@@ -0,0 +1,25 @@
+use elrond_wasm_debug::*;
+
+fn contract_map() -> ContractMap<TxContext> {
+    let mut contract_map = ContractMap::new();
+    contract_map.register_contract(
+        "file:../output/cross-chain-management.wasm",
+        Box::new(|context| Box::new(cross_chain_management::contract_obj(context))),
+    );
+    contract_map
+}
+
+#[test]
+fn access_control_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/access_control.scen.json", &contract_map());
+}
+
+#[test]
+fn allowed_senders_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/allowed_senders.scen.json", &contract_map());
+}
+
+#[test]
+fn pause_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/pause.scen.json", &contract_map());
+}
@@ -0,0 +1,102 @@
+use cross_chain_management::app_registry::AppRegistryModule;
+use cross_chain_management::*;
+use elrond_wasm::types::{Address, BoxedBytes};
+use elrond_wasm_debug::TxContext;
+
+const OWN_CHAIN_ID: u64 = 42;
+const CHAIN_ID: u64 = 5;
+const OTHER_CHAIN_ID: u64 = 6;
+
+/// The dummy context's caller is the zero address, which is made an allowed sender
+fn setup() -> impl CrossChainManagement {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    ccm.own_chain_id().set(&OWN_CHAIN_ID);
+    ccm.allowed_senders().insert(Address::zero());
+
+    ccm
+}
+
+/// The destination contract is the tx's index, so pages can be checked
+fn create_txs(ccm: &impl CrossChainManagement, to_chain_id: u64, nr_txs: usize) {
+    for index in 0..nr_txs {
+        assert!(ccm
+            .create_cross_chain_tx(
+                to_chain_id,
+                BoxedBytes::from(&[index as u8][..]),
+                BoxedBytes::from(&b"unlock"[..]),
+                BoxedBytes::from(&b"some_args"[..]),
+            )
+            .is_ok());
+    }
+}
+
+fn page(
+    ccm: &impl CrossChainManagement,
+    to_chain_id: u64,
+    from_index: usize,
+    count: usize,
+) -> Vec<u8> {
+    ccm.get_outbound_txs_by_chain(to_chain_id, from_index, count)
+        .into_vec()
+        .iter()
+        .map(|tx| tx.to_contract_address.as_slice()[0])
+        .collect()
+}
+
+#[test]
+fn empty_chain_test() {
+    let ccm = setup();
+    create_txs(&ccm, CHAIN_ID, 2);
+
+    assert_eq!(ccm.get_outbound_tx_count_by_chain(OTHER_CHAIN_ID), 0);
+    assert!(page(&ccm, OTHER_CHAIN_ID, 0, 10).is_empty());
+}
+
+#[test]
+fn page_boundary_test() {
+    let ccm = setup();
+    create_txs(&ccm, CHAIN_ID, 5);
+    create_txs(&ccm, OTHER_CHAIN_ID, 1);
+
+    assert_eq!(ccm.get_outbound_tx_count_by_chain(CHAIN_ID), 5);
+    assert_eq!(page(&ccm, CHAIN_ID, 0, 2), vec![0, 1]);
+    assert_eq!(page(&ccm, CHAIN_ID, 2, 2), vec![2, 3]);
+
+    // the last page is cut at the end of the chain's txs
+    assert_eq!(page(&ccm, CHAIN_ID, 4, 2), vec![4]);
+    assert_eq!(page(&ccm, CHAIN_ID, 0, 5), vec![0, 1, 2, 3, 4]);
+    assert_eq!(page(&ccm, OTHER_CHAIN_ID, 0, 5), vec![0]);
+}
+
+#[test]
+fn offset_past_end_test() {
+    let ccm = setup();
+    create_txs(&ccm, CHAIN_ID, 3);
+
+    assert!(page(&ccm, CHAIN_ID, 3, 10).is_empty());
+    assert!(page(&ccm, CHAIN_ID, 100, 10).is_empty());
+    assert!(page(&ccm, CHAIN_ID, usize::MAX, 10).is_empty());
+}
+
+#[test]
+fn zero_count_test() {
+    let ccm = setup();
+    create_txs(&ccm, CHAIN_ID, 3);
+
+    assert!(page(&ccm, CHAIN_ID, 0, 0).is_empty());
+}
+
+#[test]
+fn page_size_cap_test() {
+    let ccm = setup();
+    create_txs(&ccm, CHAIN_ID, MAX_OUTBOUND_TXS_PAGE_SIZE + 1);
+
+    assert_eq!(
+        page(&ccm, CHAIN_ID, 0, MAX_OUTBOUND_TXS_PAGE_SIZE + 1).len(),
+        MAX_OUTBOUND_TXS_PAGE_SIZE
+    );
+    assert_eq!(
+        page(&ccm, CHAIN_ID, MAX_OUTBOUND_TXS_PAGE_SIZE, 10),
+        vec![MAX_OUTBOUND_TXS_PAGE_SIZE as u8]
+    );
+}
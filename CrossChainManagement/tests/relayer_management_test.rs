@@ -0,0 +1,144 @@
+use access_control::{AccessControlModule, Role};
+use cross_chain_management::app_registry::AppRegistryModule;
+use cross_chain_management::*;
+use elrond_wasm::types::{Address, AsyncCallError, AsyncCallResult, BoxedBytes, SCResult, VarArgs};
+use elrond_wasm_debug::TxContext;
+
+const RELAYER_ADDRESS: [u8; 32] = [7u8; 32];
+
+/// The dummy context's caller is the zero address, which is made an admin
+fn setup() -> impl CrossChainManagement {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    ccm.role_members(Role::Admin).insert(Address::zero());
+
+    ccm
+}
+
+fn deploy_relayer(ccm: &impl CrossChainManagement) -> Address {
+    let relayer_address = Address::from(RELAYER_ADDRESS);
+
+    ccm.transaction_relayer_contract_address()
+        .set(&relayer_address);
+    ccm.add_application(
+        &relayer_address,
+        &[BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME)],
+    );
+    ccm.allowed_senders().insert(relayer_address.clone());
+
+    relayer_address
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+#[test]
+fn upgrade_without_relayer_test() {
+    let ccm = setup();
+
+    assert_err(
+        ccm.upgrade_transaction_relayer_contract(
+            BoxedBytes::from(&b"code"[..]),
+            VarArgs::from(Vec::new()),
+        ),
+        b"Transaction Relayer SC not deployed",
+    );
+}
+
+#[test]
+fn transfer_ownership_without_relayer_test() {
+    let ccm = setup();
+
+    assert_err(
+        ccm.transfer_transaction_relayer_ownership(Address::from([8u8; 32])),
+        b"Transaction Relayer SC not deployed",
+    );
+}
+
+#[test]
+fn transfer_ownership_to_zero_address_test() {
+    let ccm = setup();
+    deploy_relayer(&ccm);
+
+    assert_err(
+        ccm.transfer_transaction_relayer_ownership(Address::zero()),
+        b"New owner cannot be the zero address",
+    );
+}
+
+#[test]
+fn ownership_transferred_test() {
+    let ccm = setup();
+    let relayer_address = deploy_relayer(&ccm);
+
+    ccm.change_owner_callback(&relayer_address, AsyncCallResult::Ok(()));
+
+    assert!(ccm.transaction_relayer_contract_address().is_empty());
+    assert!(!ccm.allowed_senders().contains(&relayer_address));
+    assert!(!ccm.registered_applications().contains(&relayer_address));
+}
+
+#[test]
+fn ownership_transfer_failed_test() {
+    let ccm = setup();
+    let relayer_address = deploy_relayer(&ccm);
+
+    ccm.change_owner_callback(
+        &relayer_address,
+        AsyncCallResult::Err(AsyncCallError {
+            err_code: 4,
+            err_msg: BoxedBytes::from(&b"not the owner"[..]),
+        }),
+    );
+
+    // the relayer is still managed by this contract
+    assert_eq!(
+        ccm.transaction_relayer_contract_address().get(),
+        relayer_address
+    );
+    assert!(ccm.allowed_senders().contains(&relayer_address));
+    assert!(ccm.is_application_method_allowed(
+        &relayer_address,
+        &BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME)
+    ));
+}
+
+#[test]
+fn retire_upgradeable_relayer_test() {
+    let ccm = setup();
+    deploy_relayer(&ccm);
+
+    assert_err(
+        ccm.retire_legacy_transaction_relayer(),
+        b"Transaction Relayer is not legacy",
+    );
+}
+
+#[test]
+fn retire_legacy_relayer_test() {
+    let ccm = setup();
+    let relayer_address = deploy_relayer(&ccm);
+    ccm.legacy_transaction_relayer().set(&true);
+
+    assert_err(
+        ccm.upgrade_transaction_relayer_contract(
+            BoxedBytes::from(&b"code"[..]),
+            VarArgs::from(Vec::new()),
+        ),
+        b"Legacy Transaction Relayer cannot be upgraded, retire it and deploy a new one",
+    );
+
+    assert!(ccm.retire_legacy_transaction_relayer().is_ok());
+    assert!(ccm.transaction_relayer_contract_address().is_empty());
+    assert!(!ccm.legacy_transaction_relayer().get());
+    assert!(!ccm.allowed_senders().contains(&relayer_address));
+    assert!(!ccm.registered_applications().contains(&relayer_address));
+
+    assert_err(
+        ccm.retire_legacy_transaction_relayer(),
+        b"Transaction Relayer SC not deployed",
+    );
+}
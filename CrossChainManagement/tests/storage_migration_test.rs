@@ -0,0 +1,120 @@
+use cross_chain_management::app_registry::AppRegistryModule;
+use cross_chain_management::*;
+use elrond_wasm::elrond_codec::TopEncode;
+use elrond_wasm::types::{Address, BoxedBytes, SCResult};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction::TransactionArgs;
+
+const SOURCE_CHAIN_ID: u64 = 5;
+
+#[test]
+fn migrate_from_unversioned_storage_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+
+    assert!(ccm.migrate_storage(&[]).is_ok());
+    assert_eq!(ccm.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+    // running the migrations again is a no-op
+    assert!(ccm.migrate_storage(&[]).is_ok());
+    assert_eq!(ccm.storage_version().get(), CURRENT_STORAGE_VERSION);
+}
+
+#[test]
+fn newer_storage_version_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    ccm.storage_version().set(&(CURRENT_STORAGE_VERSION + 1));
+
+    match ccm.migrate_storage(&[]) {
+        SCResult::Ok(_) => panic!("Migration should have failed"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Storage version is newer than the contract code"[..]
+        ),
+    }
+}
+
+#[test]
+fn migrate_registers_unlock_nft_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let relayer_address = Address::from([7u8; 32]);
+
+    ccm.transaction_relayer_contract_address()
+        .set(&relayer_address);
+    ccm.registered_applications()
+        .insert(relayer_address.clone());
+    ccm.application_methods(&relayer_address)
+        .insert(BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME));
+
+    // only the step itself, as the next one queries the relayer
+    assert!(ccm.migrate_storage_from(1, &[]).is_ok());
+    assert!(ccm.is_application_method_allowed(
+        &relayer_address,
+        &BoxedBytes::from(transaction_relayer::UNLOCK_NFT_METHOD_NAME)
+    ));
+}
+
+#[test]
+fn source_contracts_migration_without_relayer_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    ccm.storage_version().set(&2);
+
+    assert!(ccm.migrate_storage(&[]).is_ok());
+    assert_eq!(ccm.storage_version().get(), CURRENT_STORAGE_VERSION);
+}
+
+#[test]
+fn migrate_registers_baseline_relayer_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let relayer_address = Address::from([7u8; 32]);
+    let source_proxy = BoxedBytes::from(&b"tx_relayer_addr_other_chain"[..]);
+
+    ccm.transaction_relayer_contract_address()
+        .set(&relayer_address);
+
+    // the next steps leave the baseline relayer alone, so they don't query it
+    assert!(ccm
+        .migrate_storage(&[(SOURCE_CHAIN_ID, source_proxy.clone())])
+        .is_ok());
+    assert_eq!(ccm.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+    assert!(ccm.legacy_transaction_relayer().get());
+    assert!(ccm.allowed_senders().contains(&relayer_address));
+    assert!(ccm.is_application_method_allowed(
+        &relayer_address,
+        &BoxedBytes::from(transaction_relayer::UNLOCK_METHOD_NAME)
+    ));
+    assert!(!ccm.is_application_method_allowed(
+        &relayer_address,
+        &BoxedBytes::from(transaction_relayer::UNLOCK_NFT_METHOD_NAME)
+    ));
+    assert_eq!(
+        ccm.get_application_source_contract(relayer_address, SOURCE_CHAIN_ID),
+        source_proxy
+    );
+}
+
+#[test]
+fn legacy_args_format_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let args = TransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(&b"WEGLD-123456"[..]),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        amount: RustBigUint::from(1_000u64),
+        from_address: BoxedBytes::empty(),
+        data: BoxedBytes::empty(),
+    };
+    let raw_args = args.to_raw_bytes().unwrap();
+
+    let mut legacy_args = Vec::new();
+    let _ = args.top_encode(&mut legacy_args);
+    let legacy_args = BoxedBytes::from(legacy_args.as_slice());
+
+    match ccm.raw_args_to_legacy(&raw_args) {
+        SCResult::Ok(converted) => assert_eq!(converted, legacy_args),
+        SCResult::Err(_) => panic!("Args should have been converted"),
+    }
+    match ccm.legacy_args_to_raw(&legacy_args) {
+        SCResult::Ok(converted) => assert_eq!(converted, raw_args),
+        SCResult::Err(_) => panic!("Args should have been converted"),
+    }
+}
@@ -0,0 +1,96 @@
+use elrond_wasm::api::CryptoApi;
+use elrond_wasm::elrond_codec::{TopDecode, TopEncode};
+use elrond_wasm::types::{BoxedBytes, H256};
+use elrond_wasm_debug::TxContext;
+use transaction::Transaction;
+
+fn transaction(method_args: &[u8]) -> Transaction {
+    Transaction {
+        source_chain_tx_hash: H256::from([1u8; 32]),
+        cross_chain_tx_id: BoxedBytes::from(&[5u8][..]),
+        from_contract_address: BoxedBytes::from(&b"proxy"[..]),
+        to_chain_id: 2,
+        to_contract_address: BoxedBytes::from(&b"app"[..]),
+        method_name: BoxedBytes::from(&b"unlock"[..]),
+        method_args: BoxedBytes::from(method_args),
+    }
+}
+
+/// Everything but the source chain tx hash, i.e. the part which is hashed
+fn serialized_partial(method_args_prefix: &[u8], method_args: &[u8]) -> Vec<u8> {
+    let mut serialized = Vec::new();
+    serialized.extend_from_slice(&[1u8, 5u8]); // cross_chain_tx_id
+    serialized.push(5u8); // from_contract_address
+    serialized.extend_from_slice(b"proxy");
+    serialized.extend_from_slice(&[2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8]); // to_chain_id
+    serialized.push(3u8); // to_contract_address
+    serialized.extend_from_slice(b"app");
+    serialized.push(6u8); // method_name
+    serialized.extend_from_slice(b"unlock");
+    serialized.extend_from_slice(method_args_prefix); // method_args
+    serialized.extend_from_slice(method_args);
+
+    serialized
+}
+
+fn serialized(method_args_prefix: &[u8], method_args: &[u8]) -> Vec<u8> {
+    let mut serialized = Vec::new();
+    serialized.push(32u8); // source_chain_tx_hash
+    serialized.extend_from_slice(&[1u8; 32]);
+    serialized.extend_from_slice(&serialized_partial(method_args_prefix, method_args));
+
+    serialized
+}
+
+fn assert_round_trip(method_args_prefix: &[u8], method_args: &[u8]) {
+    let expected = serialized(method_args_prefix, method_args);
+
+    let mut encoded = Vec::new();
+    transaction(method_args).top_encode(&mut encoded).unwrap();
+    assert_eq!(encoded, expected);
+
+    let decoded = Transaction::top_decode(expected.as_slice()).unwrap();
+    assert_eq!(decoded.source_chain_tx_hash, H256::from([1u8; 32]));
+    assert_eq!(decoded.cross_chain_tx_id, BoxedBytes::from(&[5u8][..]));
+    assert_eq!(
+        decoded.from_contract_address,
+        BoxedBytes::from(&b"proxy"[..])
+    );
+    assert_eq!(decoded.to_chain_id, 2);
+    assert_eq!(decoded.to_contract_address, BoxedBytes::from(&b"app"[..]));
+    assert_eq!(decoded.method_name, BoxedBytes::from(&b"unlock"[..]));
+    assert_eq!(decoded.method_args, BoxedBytes::from(method_args));
+}
+
+#[test]
+fn transaction_round_trip_test() {
+    assert_round_trip(&[3u8], &[0xaa, 0xbb, 0xcc]);
+}
+
+#[test]
+fn transaction_long_method_args_round_trip_test() {
+    // lengths of 0xfd and above are prefixed by 0xfd and a 2-byte length
+    assert_round_trip(&[0xfd, 0x2c, 0x01], &[7u8; 300]);
+}
+
+#[test]
+fn transaction_empty_method_args_round_trip_test() {
+    assert_round_trip(&[0u8], &[]);
+}
+
+#[test]
+fn transaction_truncated_input_test() {
+    let expected = serialized(&[3u8], &[0xaa, 0xbb, 0xcc]);
+
+    assert!(Transaction::top_decode(&expected[..expected.len() - 1]).is_err());
+}
+
+#[test]
+fn transaction_hash_excludes_source_chain_tx_hash_test() {
+    let tx = transaction(&[0xaa, 0xbb, 0xcc]);
+
+    assert_eq!(
+        tx.calculate_hash(TxContext::dummy()),
+        TxContext::dummy().sha256(&serialized_partial(&[3u8], &[0xaa, 0xbb, 0xcc]))
+    );
+}
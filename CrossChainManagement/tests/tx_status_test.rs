@@ -0,0 +1,144 @@
+use cross_chain_management::tx_status::TxStatusModule;
+use elrond_wasm::elrond_codec::TopEncode;
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, H256};
+use elrond_wasm_debug::TxContext;
+
+use transaction::TransactionStatus;
+
+const CHAIN_ID: u64 = 5;
+
+#[test]
+fn status_transitions_test() {
+    assert!(TransactionStatus::None.can_transition_to(TransactionStatus::Pending));
+    assert!(TransactionStatus::None.can_transition_to(TransactionStatus::Executed));
+    assert!(TransactionStatus::Pending.can_transition_to(TransactionStatus::InProgress));
+    assert!(TransactionStatus::InProgress.can_transition_to(TransactionStatus::Rejected));
+    assert!(TransactionStatus::None.can_transition_to(TransactionStatus::Failed));
+    assert!(TransactionStatus::Failed.can_transition_to(TransactionStatus::Executed));
+
+    assert!(!TransactionStatus::None.can_transition_to(TransactionStatus::Rejected));
+    assert!(!TransactionStatus::InProgress.can_transition_to(TransactionStatus::Pending));
+    assert!(!TransactionStatus::Executed.can_transition_to(TransactionStatus::Rejected));
+    assert!(!TransactionStatus::Rejected.can_transition_to(TransactionStatus::Executed));
+    assert!(!TransactionStatus::Failed.can_transition_to(TransactionStatus::Rejected));
+    assert!(!TransactionStatus::Executed.can_transition_to(TransactionStatus::Failed));
+    assert!(!TransactionStatus::Pending.can_transition_to(TransactionStatus::Failed));
+}
+
+#[test]
+fn change_tx_status_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let tx_hash = H256::from([1u8; 32]);
+
+    assert!(ccm.get_tx_status(CHAIN_ID, tx_hash.clone()) == TransactionStatus::None);
+
+    assert!(ccm
+        .change_tx_status(CHAIN_ID, &tx_hash, TransactionStatus::Pending)
+        .is_ok());
+    assert!(ccm
+        .change_tx_status(CHAIN_ID, &tx_hash, TransactionStatus::Executed)
+        .is_ok());
+    assert!(ccm.get_tx_status(CHAIN_ID, tx_hash.clone()) == TransactionStatus::Executed);
+
+    match ccm.change_tx_status(CHAIN_ID, &tx_hash, TransactionStatus::Rejected) {
+        SCResult::Ok(_) => panic!("Executed txs should not change status"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Invalid transaction status transition"[..]
+        ),
+    }
+}
+
+fn encoded(status: TransactionStatus) -> BoxedBytes {
+    let mut serialized = Vec::new();
+    status.top_encode(&mut serialized).unwrap();
+
+    serialized.into()
+}
+
+#[test]
+fn dispatched_tx_status_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+
+    // applications returning nothing executed the tx
+    assert!(ccm.get_dispatched_tx_status(&[]) == TransactionStatus::Executed);
+    assert!(
+        ccm.get_dispatched_tx_status(&[encoded(TransactionStatus::Executed)])
+            == TransactionStatus::Executed
+    );
+    assert!(
+        ccm.get_dispatched_tx_status(&[encoded(TransactionStatus::Failed)])
+            == TransactionStatus::Failed
+    );
+
+    // other results are not statuses
+    assert!(
+        ccm.get_dispatched_tx_status(&[BoxedBytes::from(&b"some result"[..])])
+            == TransactionStatus::Executed
+    );
+}
+
+fn encoded_id(failed_tx_id: u64) -> BoxedBytes {
+    let mut serialized = Vec::new();
+    failed_tx_id.top_encode(&mut serialized).unwrap();
+
+    serialized.into()
+}
+
+/// The dummy context's caller is the zero address, so it acts as the application
+#[test]
+fn failed_tx_reported_executed_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let tx_hash = H256::from([1u8; 32]);
+
+    let results = [encoded(TransactionStatus::Failed), encoded_id(3)];
+    ccm.record_failed_tx(&Address::zero(), &results, CHAIN_ID, &tx_hash);
+    assert!(ccm
+        .change_tx_status(CHAIN_ID, &tx_hash, TransactionStatus::Failed)
+        .is_ok());
+
+    assert!(ccm.report_failed_tx_executed(3).is_ok());
+    assert!(ccm.get_tx_status(CHAIN_ID, tx_hash.clone()) == TransactionStatus::Executed);
+    assert!(ccm.failed_tx(&Address::zero(), 3).is_empty());
+
+    // reporting it again is a no-op
+    assert!(ccm.report_failed_tx_executed(3).is_ok());
+    assert!(ccm.get_tx_status(CHAIN_ID, tx_hash) == TransactionStatus::Executed);
+}
+
+#[test]
+fn failed_tx_of_other_app_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let tx_hash = H256::from([1u8; 32]);
+
+    let results = [encoded(TransactionStatus::Failed), encoded_id(3)];
+    ccm.record_failed_tx(&Address::from([9u8; 32]), &results, CHAIN_ID, &tx_hash);
+    assert!(ccm
+        .change_tx_status(CHAIN_ID, &tx_hash, TransactionStatus::Failed)
+        .is_ok());
+
+    // only the application which returned the ID can report it
+    assert!(ccm.report_failed_tx_executed(3).is_ok());
+    assert!(ccm.get_tx_status(CHAIN_ID, tx_hash) == TransactionStatus::Failed);
+}
+
+#[test]
+fn failed_tx_without_id_test() {
+    let ccm = cross_chain_management::contract_obj(TxContext::dummy());
+    let tx_hash = H256::from([1u8; 32]);
+
+    ccm.record_failed_tx(
+        &Address::zero(),
+        &[encoded(TransactionStatus::Failed)],
+        CHAIN_ID,
+        &tx_hash,
+    );
+    ccm.record_failed_tx(
+        &Address::zero(),
+        &[encoded(TransactionStatus::Failed), encoded_id(0)],
+        CHAIN_ID,
+        &tx_hash,
+    );
+
+    assert!(ccm.failed_tx(&Address::zero(), 0).is_empty());
+}
@@ -0,0 +1,58 @@
+ALICE="/home/elrond/elrond-sdk/erdpy/testnet/wallets/users/alice.pem"
+ADDRESS=$(erdpy data load --key=address-testnet-esdtTokenManager)
+DEPLOY_TRANSACTION=$(erdpy data load --key=deployTransaction-testnet-esdtTokenManager)
+PROXY=http://localhost:7950 # For public testnet, replace with https://testnet-gateway.elrond.com
+CHAIN_ID=local-testnet
+PROJECT_HARDCODED="/home/elrond/sc-polynetwork-bridge-rs/EsdtTokenManager"
+
+# 0.05 eGLD
+ESDT_ISSUE_COST=50000000000000000
+
+# "WrappedEGLD" and "WEGLD"
+WRAPPED_EGLD_DISPLAY_NAME=0x5772617070656445474c44
+WRAPPED_EGLD_TICKER=0x5745474c44
+
+# "unwrapEgld"
+UNWRAP_EGLD_FUNCTION_NAME=0x756e7772617045676c64
+
+# To get tx result, go to http://localhost:7950/transaction/tx_hash_here?withResults=true
+
+deploy() {
+    erdpy --verbose contract deploy --project=${PROJECT_HARDCODED} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=100000000 --send --outfile="deploy-testnet.interaction.json" --proxy=${PROXY} --chain=${CHAIN_ID} || return
+
+    TRANSACTION=$(erdpy data parse --file="deploy-testnet.interaction.json" --expression="data['emitted_tx']['hash']")
+    ADDRESS=$(erdpy data parse --file="deploy-testnet.interaction.json" --expression="data['emitted_tx']['address']")
+
+    erdpy data store --key=address-testnet-esdtTokenManager --value=${ADDRESS}
+    erdpy data store --key=deployTransaction-testnet-esdtTokenManager --value=${TRANSACTION}
+
+    echo ""
+    echo "Smart contract address: ${ADDRESS}"
+}
+
+# Arguments: initial supply, which stays in the contract for the first wraps
+issueWrappedEgld() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=100000000 --value=${ESDT_ISSUE_COST} --function="performWrappedEgldIssue" --arguments ${WRAPPED_EGLD_DISPLAY_NAME} ${WRAPPED_EGLD_TICKER} $1 --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
+setLocalRoles() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=100000000 --function="setLocalRoles" --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
+# Arguments: eGLD amount
+wrapEgld() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=10000000 --value=$1 --function="wrapEgld" --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
+# Arguments: wrapped eGLD token identifier, amount
+unwrapEgld() {
+    erdpy --verbose contract call ${ADDRESS} --nonce=${alice_nonce} --pem=${ALICE} --gas-limit=10000000 --function="ESDTTransfer" --arguments $1 $2 ${UNWRAP_EGLD_FUNCTION_NAME} --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
+getWrappedEgldTokenIdentifier() {
+    erdpy --verbose contract query ${ADDRESS} --function="getWrappedEgldTokenIdentifier" --proxy=${PROXY}
+}
+
+getLockedEgldBalance() {
+    erdpy --verbose contract query ${ADDRESS} --function="getLockedEgldBalance" --proxy=${PROXY}
+}
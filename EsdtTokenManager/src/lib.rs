@@ -0,0 +1,197 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+const WRAPPED_EGLD_DECIMALS: usize = 18;
+
+/// Wraps eGLD into a fungible ESDT issued and owned by this contract, and unwraps it back.
+/// Every wrapped eGLD in circulation is backed by an eGLD held by this contract.
+#[elrond_wasm_derive::contract]
+pub trait EsdtTokenManager {
+    #[init]
+    fn init(&self) {}
+
+    // endpoints - owner-only
+
+    /// The initial supply stays in the contract, and is used for the first wraps
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(performWrappedEgldIssue)]
+    fn perform_wrapped_egld_issue(
+        &self,
+        token_display_name: BoxedBytes,
+        token_ticker: BoxedBytes,
+        initial_supply: Self::BigUint,
+        #[payment] issue_cost: Self::BigUint,
+    ) -> SCResult<AsyncCall<Self::SendApi>> {
+        require!(
+            self.wrapped_egld_token_id().is_empty(),
+            "Wrapped eGLD was already issued"
+        );
+        require!(initial_supply > 0, "Initial supply must be more than 0");
+
+        let caller = self.blockchain().get_caller();
+
+        Ok(ESDTSystemSmartContractProxy::new_proxy_obj(self.send())
+            .issue_fungible(
+                issue_cost,
+                &token_display_name,
+                &token_ticker,
+                &initial_supply,
+                FungibleTokenProperties {
+                    num_decimals: WRAPPED_EGLD_DECIMALS,
+                    can_freeze: false,
+                    can_wipe: false,
+                    can_pause: false,
+                    can_mint: true,
+                    can_burn: false,
+                    can_change_owner: false,
+                    can_upgrade: true,
+                    can_add_special_roles: true,
+                },
+            )
+            .async_call()
+            .with_callback(self.callbacks().esdt_issue_callback(&caller)))
+    }
+
+    /// Gives this contract the local mint role for the wrapped eGLD
+    #[only_owner]
+    #[endpoint(setLocalRoles)]
+    fn set_local_roles(&self) -> SCResult<AsyncCall<Self::SendApi>> {
+        require!(
+            !self.wrapped_egld_token_id().is_empty(),
+            "Wrapped eGLD was not issued yet"
+        );
+
+        let token_id = self.wrapped_egld_token_id().get();
+
+        Ok(ESDTSystemSmartContractProxy::new_proxy_obj(self.send())
+            .set_special_roles(
+                &self.blockchain().get_sc_address(),
+                token_id.as_esdt_identifier(),
+                &[EsdtLocalRole::Mint],
+            )
+            .async_call())
+    }
+
+    // endpoints
+
+    #[payable("EGLD")]
+    #[endpoint(wrapEgld)]
+    fn wrap_egld(&self, #[payment] payment: Self::BigUint) -> SCResult<()> {
+        require!(payment > 0, "Payment must be more than 0");
+        require!(
+            !self.wrapped_egld_token_id().is_empty(),
+            "Wrapped eGLD was not issued yet"
+        );
+
+        let token_id = self.wrapped_egld_token_id().get();
+        let mut unused_wrapped_egld = self.unused_wrapped_egld().get();
+        if unused_wrapped_egld < payment {
+            let extra_amount = &payment - &unused_wrapped_egld;
+            self.send().esdt_local_mint(&token_id, 0, &extra_amount);
+
+            unused_wrapped_egld += extra_amount;
+        }
+
+        unused_wrapped_egld -= &payment;
+        self.unused_wrapped_egld().set(&unused_wrapped_egld);
+
+        let caller = self.blockchain().get_caller();
+        self.send()
+            .direct(&caller, &token_id, 0, &payment, b"wrapping");
+
+        self.wrap_egld_event(&caller, &payment);
+
+        Ok(())
+    }
+
+    /// Sends the eGLD to the caller, or to `opt_to` if provided,
+    /// which lets other contracts unwrap directly to their users
+    #[payable("*")]
+    #[endpoint(unwrapEgld)]
+    fn unwrap_egld(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_amount] payment_amount: Self::BigUint,
+        #[var_args] opt_to: OptionalArg<Address>,
+    ) -> SCResult<()> {
+        require!(payment_amount > 0, "Must pay more than 0 tokens");
+        require!(
+            !self.wrapped_egld_token_id().is_empty(),
+            "Wrapped eGLD was not issued yet"
+        );
+        require!(
+            payment_token == self.wrapped_egld_token_id().get(),
+            "Wrong esdt token"
+        );
+
+        let to = opt_to
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_caller());
+
+        self.unused_wrapped_egld()
+            .update(|unused| *unused += &payment_amount);
+        self.send().direct_egld(&to, &payment_amount, b"unwrapping");
+
+        self.unwrap_egld_event(&to, &payment_amount);
+
+        Ok(())
+    }
+
+    // views
+
+    #[view(getWrappedEgldTokenIdentifier)]
+    fn get_wrapped_egld_token_id(&self) -> TokenIdentifier {
+        self.wrapped_egld_token_id().get()
+    }
+
+    #[view(getLockedEgldBalance)]
+    fn get_locked_egld_balance(&self) -> Self::BigUint {
+        self.blockchain()
+            .get_balance(&self.blockchain().get_sc_address())
+    }
+
+    // callbacks
+
+    #[callback]
+    fn esdt_issue_callback(
+        &self,
+        caller: &Address,
+        #[payment_token] token_id: TokenIdentifier,
+        #[payment] returned_tokens: Self::BigUint,
+        #[call_result] result: AsyncCallResult<()>,
+    ) {
+        match result {
+            AsyncCallResult::Ok(()) => {
+                if token_id.is_esdt() {
+                    self.wrapped_egld_token_id().set(&token_id);
+                    self.unused_wrapped_egld().set(&returned_tokens);
+                }
+            }
+            AsyncCallResult::Err(_) => {
+                // refund the issue cost
+                if token_id.is_egld() && returned_tokens > 0 {
+                    self.send().direct_egld(caller, &returned_tokens, &[]);
+                }
+            }
+        }
+    }
+
+    // events
+
+    #[event("wrapEgld")]
+    fn wrap_egld_event(&self, #[indexed] user: &Address, amount: &Self::BigUint);
+
+    #[event("unwrapEgld")]
+    fn unwrap_egld_event(&self, #[indexed] user: &Address, amount: &Self::BigUint);
+
+    // storage
+
+    #[storage_mapper("wrappedEgldTokenId")]
+    fn wrapped_egld_token_id(&self) -> SingleValueMapper<Self::Storage, TokenIdentifier>;
+
+    /// Wrapped eGLD owned by this contract, which is not in circulation
+    #[storage_mapper("unusedWrappedEgld")]
+    fn unused_wrapped_egld(&self) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+}
@@ -0,0 +1,158 @@
+use elrond_wasm::types::{
+    Address, AsyncCallResult, BoxedBytes, OptionalArg, SCResult, TokenIdentifier,
+};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use esdt_token_manager::*;
+
+const WRAPPED_EGLD_TOKEN_ID: &[u8] = b"WEGLD-123456";
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn issued_token_manager() -> impl EsdtTokenManager {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+    token_manager
+        .wrapped_egld_token_id()
+        .set(&TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID));
+
+    token_manager
+}
+
+#[test]
+fn wrap_before_issue_test() {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+
+    assert_err(
+        token_manager.wrap_egld(RustBigUint::from(1_000u64)),
+        b"Wrapped eGLD was not issued yet",
+    );
+}
+
+#[test]
+fn wrap_zero_test() {
+    let token_manager = issued_token_manager();
+
+    assert_err(
+        token_manager.wrap_egld(RustBigUint::from(0u64)),
+        b"Payment must be more than 0",
+    );
+}
+
+#[test]
+fn unwrap_before_issue_test() {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+
+    assert_err(
+        token_manager.unwrap_egld(
+            TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID),
+            RustBigUint::from(1_000u64),
+            OptionalArg::None,
+        ),
+        b"Wrapped eGLD was not issued yet",
+    );
+}
+
+#[test]
+fn unwrap_wrong_token_test() {
+    let token_manager = issued_token_manager();
+
+    assert_err(
+        token_manager.unwrap_egld(
+            TokenIdentifier::from(&b"OTHER-123456"[..]),
+            RustBigUint::from(1_000u64),
+            OptionalArg::None,
+        ),
+        b"Wrong esdt token",
+    );
+    assert_eq!(
+        token_manager.unused_wrapped_egld().get(),
+        RustBigUint::from(0u64)
+    );
+}
+
+#[test]
+fn unwrap_zero_test() {
+    let token_manager = issued_token_manager();
+
+    assert_err(
+        token_manager.unwrap_egld(
+            TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID),
+            RustBigUint::from(0u64),
+            OptionalArg::None,
+        ),
+        b"Must pay more than 0 tokens",
+    );
+}
+
+#[test]
+fn issue_twice_test() {
+    let token_manager = issued_token_manager();
+
+    assert_err(
+        token_manager.perform_wrapped_egld_issue(
+            BoxedBytes::from(&b"WrappedEGLD"[..]),
+            BoxedBytes::from(&b"WEGLD"[..]),
+            RustBigUint::from(0u64),
+            RustBigUint::from(50_000_000_000_000_000u64),
+        ),
+        b"Wrapped eGLD was already issued",
+    );
+}
+
+#[test]
+fn issue_zero_supply_test() {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+
+    assert_err(
+        token_manager.perform_wrapped_egld_issue(
+            BoxedBytes::from(&b"WrappedEGLD"[..]),
+            BoxedBytes::from(&b"WEGLD"[..]),
+            RustBigUint::from(0u64),
+            RustBigUint::from(50_000_000_000_000_000u64),
+        ),
+        b"Initial supply must be more than 0",
+    );
+}
+
+#[test]
+fn issue_callback_test() {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+
+    // a successful callback without the issued tokens does not set the token ID
+    token_manager.esdt_issue_callback(
+        &Address::zero(),
+        TokenIdentifier::egld(),
+        RustBigUint::from(0u64),
+        AsyncCallResult::Ok(()),
+    );
+    assert!(token_manager.wrapped_egld_token_id().is_empty());
+
+    token_manager.esdt_issue_callback(
+        &Address::zero(),
+        TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID),
+        RustBigUint::from(1_000u64),
+        AsyncCallResult::Ok(()),
+    );
+    assert_eq!(
+        token_manager.wrapped_egld_token_id().get(),
+        TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID)
+    );
+    assert_eq!(
+        token_manager.unused_wrapped_egld().get(),
+        RustBigUint::from(1_000u64)
+    );
+}
+
+#[test]
+fn set_local_roles_before_issue_test() {
+    let token_manager = esdt_token_manager::contract_obj(TxContext::dummy());
+
+    assert_err(
+        token_manager.set_local_roles(),
+        b"Wrapped eGLD was not issued yet",
+    );
+}
@@ -0,0 +1,23 @@
+use elrond_wasm::types::{Address, BoxedBytes, Vec};
+
+elrond_wasm::derive_imports!();
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum Action {
+    Nothing,
+    AddBoardMember(Address),
+    RemoveBoardMember(Address),
+    ChangeQuorum(usize),
+    SCCall {
+        to: Address,
+        endpoint_name: BoxedBytes,
+        arguments: Vec<BoxedBytes>,
+    },
+}
+
+impl Action {
+    /// Performed or discarded actions are replaced with `Nothing`
+    pub fn is_pending(&self) -> bool {
+        !matches!(*self, Action::Nothing)
+    }
+}
@@ -0,0 +1,314 @@
+#![no_std]
+
+mod action;
+
+pub use action::Action;
+
+elrond_wasm::imports!();
+
+/// Multisig board which owns the bridge contracts.
+/// Any board member can propose an action, which can be performed once `quorum` board members signed it.
+#[elrond_wasm_derive::contract]
+pub trait Governance {
+    #[init]
+    fn init(&self, quorum: usize, #[var_args] board: VarArgs<Address>) -> SCResult<()> {
+        require!(!board.is_empty(), "Board cannot be empty");
+        require!(quorum > 0, "Quorum cannot be 0");
+        require!(quorum <= board.len(), "Quorum cannot exceed board size");
+
+        let mut board_mapper = self.board_members();
+        for member in board.into_vec() {
+            require!(board_mapper.insert(member), "Duplicate board member");
+        }
+        self.quorum().set(&quorum);
+
+        Ok(())
+    }
+
+    // endpoints - board members only
+
+    #[endpoint(proposeAddBoardMember)]
+    fn propose_add_board_member(&self, board_member: Address) -> SCResult<usize> {
+        self.propose_action(Action::AddBoardMember(board_member))
+    }
+
+    #[endpoint(proposeRemoveBoardMember)]
+    fn propose_remove_board_member(&self, board_member: Address) -> SCResult<usize> {
+        self.propose_action(Action::RemoveBoardMember(board_member))
+    }
+
+    #[endpoint(proposeChangeQuorum)]
+    fn propose_change_quorum(&self, new_quorum: usize) -> SCResult<usize> {
+        self.propose_action(Action::ChangeQuorum(new_quorum))
+    }
+
+    /// Proposes a call to any endpoint of any contract owned by this one,
+    /// e.g. syncGenesisHeader on BlockHeaderSync or setTransactionRelayerAssetHash on CrossChainManagement
+    #[endpoint(proposeSCCall)]
+    fn propose_sc_call(
+        &self,
+        to: Address,
+        endpoint_name: BoxedBytes,
+        #[var_args] arguments: VarArgs<BoxedBytes>,
+    ) -> SCResult<usize> {
+        require!(!endpoint_name.is_empty(), "Endpoint name cannot be empty");
+
+        self.propose_action(Action::SCCall {
+            to,
+            endpoint_name,
+            arguments: arguments.into_vec(),
+        })
+    }
+
+    #[endpoint]
+    fn sign(&self, action_id: usize) -> SCResult<()> {
+        self.require_board_member()?;
+        self.require_pending_action(action_id)?;
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.action_signers(action_id).insert(caller),
+            "Action already signed"
+        );
+
+        Ok(())
+    }
+
+    #[endpoint]
+    fn unsign(&self, action_id: usize) -> SCResult<()> {
+        self.require_board_member()?;
+        self.require_pending_action(action_id)?;
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.action_signers(action_id).remove(&caller),
+            "Action not signed"
+        );
+
+        Ok(())
+    }
+
+    /// Actions can only be discarded once all signers unsigned them
+    #[endpoint(discardAction)]
+    fn discard_action(&self, action_id: usize) -> SCResult<()> {
+        self.require_board_member()?;
+        self.require_pending_action(action_id)?;
+        require!(
+            self.get_valid_signer_count(action_id) == 0,
+            "Cannot discard action with valid signatures"
+        );
+
+        self.clear_action(action_id);
+
+        Ok(())
+    }
+
+    #[endpoint(performAction)]
+    fn perform_action(&self, action_id: usize) -> SCResult<()> {
+        self.require_board_member()?;
+        self.require_pending_action(action_id)?;
+        require!(
+            self.quorum_reached(action_id),
+            "Quorum has not been reached"
+        );
+
+        let action = self.actions().get(action_id);
+        self.clear_action(action_id);
+
+        match action {
+            Action::Nothing => {}
+            Action::AddBoardMember(board_member) => {
+                require!(
+                    self.board_members().insert(board_member),
+                    "Address is already a board member"
+                );
+            }
+            Action::RemoveBoardMember(board_member) => {
+                require!(
+                    self.board_members().len() > self.quorum().get(),
+                    "Board size cannot go below quorum"
+                );
+                require!(
+                    self.board_members().remove(&board_member),
+                    "Address is not a board member"
+                );
+
+                self.remove_signatures(&board_member);
+            }
+            Action::ChangeQuorum(new_quorum) => {
+                require!(new_quorum > 0, "Quorum cannot be 0");
+                require!(
+                    new_quorum <= self.board_members().len(),
+                    "Quorum cannot exceed board size"
+                );
+
+                self.quorum().set(&new_quorum);
+            }
+            Action::SCCall {
+                to,
+                endpoint_name,
+                arguments,
+            } => {
+                let mut arg_buffer = ArgBuffer::new();
+                for arg in &arguments {
+                    arg_buffer.push_argument_bytes(arg.as_slice());
+                }
+
+                let _ = self.send().execute_on_dest_context_raw(
+                    self.blockchain().get_gas_left(),
+                    &to,
+                    &Self::BigUint::zero(),
+                    endpoint_name.as_slice(),
+                    &arg_buffer,
+                );
+            }
+        }
+
+        self.action_performed_event(action_id);
+
+        Ok(())
+    }
+
+    // views
+
+    #[view(getBoardMembers)]
+    fn get_board_members(&self) -> MultiResultVec<Address> {
+        self.board_members().iter().collect::<Vec<Address>>().into()
+    }
+
+    #[view(getActionData)]
+    fn get_action_data(&self, action_id: usize) -> SCResult<Action> {
+        self.require_action_exists(action_id)?;
+
+        Ok(self.actions().get(action_id))
+    }
+
+    #[view(getActionSigners)]
+    fn get_action_signers(&self, action_id: usize) -> MultiResultVec<Address> {
+        self.action_signers(action_id)
+            .iter()
+            .collect::<Vec<Address>>()
+            .into()
+    }
+
+    /// Only signatures of current board members are counted
+    #[view(getActionValidSignerCount)]
+    fn get_valid_signer_count(&self, action_id: usize) -> usize {
+        let board_members = self.board_members();
+
+        self.action_signers(action_id)
+            .iter()
+            .filter(|signer| board_members.contains(signer))
+            .count()
+    }
+
+    #[view(quorumReached)]
+    fn quorum_reached(&self, action_id: usize) -> bool {
+        self.get_valid_signer_count(action_id) >= self.quorum().get()
+    }
+
+    #[view(getActionLastIndex)]
+    fn get_action_last_index(&self) -> usize {
+        self.actions().len()
+    }
+
+    #[view(getPendingActionIds)]
+    fn get_pending_action_ids(&self) -> MultiResultVec<usize> {
+        self.pending_action_ids()
+            .iter()
+            .collect::<Vec<usize>>()
+            .into()
+    }
+
+    // private
+
+    /// Stores the action and signs it on behalf of the proposer
+    fn propose_action(&self, action: Action) -> SCResult<usize> {
+        self.require_board_member()?;
+
+        let action_id = self.actions().push(&action);
+        self.pending_action_ids().insert(action_id);
+        self.action_signers(action_id)
+            .insert(self.blockchain().get_caller());
+
+        self.action_proposed_event(action_id, &action);
+
+        Ok(action_id)
+    }
+
+    fn clear_action(&self, action_id: usize) {
+        self.actions().set(action_id, &Action::Nothing);
+        self.pending_action_ids().remove(&action_id);
+
+        let mut signers_mapper = self.action_signers(action_id);
+        let signers: Vec<Address> = signers_mapper.iter().collect();
+        for signer in &signers {
+            signers_mapper.remove(signer);
+        }
+    }
+
+    /// Removes the signatures of a former board member,
+    /// so they don't count again if the address is added back to the board
+    fn remove_signatures(&self, signer: &Address) {
+        for action_id in self.pending_action_ids().iter() {
+            self.action_signers(action_id).remove(signer);
+        }
+    }
+
+    fn require_board_member(&self) -> SCResult<()> {
+        require!(
+            self.board_members()
+                .contains(&self.blockchain().get_caller()),
+            "Only board members may call this function"
+        );
+
+        Ok(())
+    }
+
+    fn require_action_exists(&self, action_id: usize) -> SCResult<()> {
+        require!(
+            action_id > 0 && action_id <= self.actions().len(),
+            "Action does not exist"
+        );
+
+        Ok(())
+    }
+
+    fn require_pending_action(&self, action_id: usize) -> SCResult<()> {
+        self.require_action_exists(action_id)?;
+        require!(
+            self.pending_action_ids().contains(&action_id),
+            "Action was already performed or discarded"
+        );
+
+        Ok(())
+    }
+
+    // events
+
+    #[event("actionProposed")]
+    fn action_proposed_event(&self, #[indexed] action_id: usize, action: &Action);
+
+    #[event("actionPerformed")]
+    fn action_performed_event(&self, #[indexed] action_id: usize);
+
+    // storage
+
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<Self::Storage, usize>;
+
+    #[storage_mapper("boardMembers")]
+    fn board_members(&self) -> SetMapper<Self::Storage, Address>;
+
+    /// Action IDs start from 1
+    #[storage_mapper("actions")]
+    fn actions(&self) -> VecMapper<Self::Storage, Action>;
+
+    /// Actions which were neither performed nor discarded
+    #[storage_mapper("pendingActionIds")]
+    fn pending_action_ids(&self) -> SetMapper<Self::Storage, usize>;
+
+    #[storage_mapper("actionSigners")]
+    fn action_signers(&self, action_id: usize) -> SetMapper<Self::Storage, Address>;
+}
@@ -0,0 +1,303 @@
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, VarArgs};
+use elrond_wasm_debug::TxContext;
+use governance::*;
+
+// the dummy context's caller
+const CALLER: [u8; 32] = [0u8; 32];
+const ALICE: [u8; 32] = [1u8; 32];
+const BOB: [u8; 32] = [2u8; 32];
+const CAROL: [u8; 32] = [3u8; 32];
+
+fn setup(quorum: usize, board: &[[u8; 32]]) -> impl Governance {
+    let governance = governance::contract_obj(TxContext::dummy());
+    let board: Vec<Address> = board.iter().map(|member| Address::from(*member)).collect();
+
+    assert!(governance.init(quorum, VarArgs::from(board)).is_ok());
+
+    governance
+}
+
+/// Signatures of other board members are added directly, as the caller cannot be changed
+fn sign_as(governance: &impl Governance, action_id: usize, signer: [u8; 32]) {
+    governance
+        .action_signers(action_id)
+        .insert(Address::from(signer));
+}
+
+fn ok<T>(result: SCResult<T>) -> T {
+    match result {
+        SCResult::Ok(value) => value,
+        SCResult::Err(err) => panic!("Call failed: {:?}", err.as_bytes()),
+    }
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+#[test]
+fn init_test() {
+    let governance = governance::contract_obj(TxContext::dummy());
+
+    assert_err(
+        governance.init(1, VarArgs::from(Vec::new())),
+        b"Board cannot be empty",
+    );
+    assert_err(
+        governance.init(0, VarArgs::from(vec![Address::from(ALICE)])),
+        b"Quorum cannot be 0",
+    );
+    assert_err(
+        governance.init(2, VarArgs::from(vec![Address::from(ALICE)])),
+        b"Quorum cannot exceed board size",
+    );
+    assert_err(
+        governance.init(
+            1,
+            VarArgs::from(vec![Address::from(ALICE), Address::from(ALICE)]),
+        ),
+        b"Duplicate board member",
+    );
+}
+
+#[test]
+fn only_board_members_test() {
+    let governance = setup(1, &[ALICE, BOB]);
+
+    assert_err(
+        governance.propose_change_quorum(2),
+        b"Only board members may call this function",
+    );
+}
+
+#[test]
+fn proposer_signs_action_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+
+    let action_id = ok(governance.propose_change_quorum(1));
+    assert_eq!(action_id, 1);
+    assert_eq!(governance.get_action_last_index(), 1);
+    assert!(governance
+        .action_signers(action_id)
+        .contains(&Address::from(CALLER)));
+    assert_eq!(governance.get_valid_signer_count(action_id), 1);
+    assert!(!governance.quorum_reached(action_id));
+}
+
+#[test]
+fn quorum_not_reached_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_change_quorum(1));
+
+    assert_err(
+        governance.perform_action(action_id),
+        b"Quorum has not been reached",
+    );
+}
+
+#[test]
+fn perform_action_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_change_quorum(1));
+    sign_as(&governance, action_id, ALICE);
+
+    assert!(governance.quorum_reached(action_id));
+    assert!(governance.perform_action(action_id).is_ok());
+    assert_eq!(governance.quorum().get(), 1);
+
+    // performed actions are cleared
+    assert!(!ok(governance.get_action_data(action_id)).is_pending());
+    assert_eq!(governance.get_valid_signer_count(action_id), 0);
+    assert_err(
+        governance.sign(action_id),
+        b"Action was already performed or discarded",
+    );
+    assert_err(
+        governance.perform_action(action_id),
+        b"Action was already performed or discarded",
+    );
+}
+
+#[test]
+fn sign_unsign_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_change_quorum(1));
+
+    assert_err(governance.sign(action_id), b"Action already signed");
+
+    assert!(governance.unsign(action_id).is_ok());
+    assert_eq!(governance.get_valid_signer_count(action_id), 0);
+    assert_err(governance.unsign(action_id), b"Action not signed");
+
+    assert!(governance.sign(action_id).is_ok());
+    assert_eq!(governance.get_valid_signer_count(action_id), 1);
+
+    assert_err(governance.sign(action_id + 1), b"Action does not exist");
+    assert_err(governance.sign(0), b"Action does not exist");
+}
+
+#[test]
+fn discard_action_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_change_quorum(1));
+    sign_as(&governance, action_id, ALICE);
+    assert!(governance.unsign(action_id).is_ok());
+
+    assert_err(
+        governance.discard_action(action_id),
+        b"Cannot discard action with valid signatures",
+    );
+
+    governance
+        .action_signers(action_id)
+        .remove(&Address::from(ALICE));
+    // signatures of non-members are not valid
+    sign_as(&governance, action_id, CAROL);
+
+    assert!(governance.discard_action(action_id).is_ok());
+    assert!(!ok(governance.get_action_data(action_id)).is_pending());
+    assert_err(
+        governance.perform_action(action_id),
+        b"Action was already performed or discarded",
+    );
+}
+
+#[test]
+fn pending_action_ids_test() {
+    let governance = setup(1, &[CALLER, ALICE]);
+    let performed_action_id = ok(governance.propose_change_quorum(1));
+    let discarded_action_id = ok(governance.propose_change_quorum(1));
+    let pending_action_id = ok(governance.propose_change_quorum(1));
+
+    assert!(governance.perform_action(performed_action_id).is_ok());
+    assert!(governance.unsign(discarded_action_id).is_ok());
+    assert!(governance.discard_action(discarded_action_id).is_ok());
+
+    let pending_action_ids: Vec<usize> = governance.pending_action_ids().iter().collect();
+    assert_eq!(pending_action_ids, vec![pending_action_id]);
+
+    // removing a board member only touches the signers of pending actions
+    sign_as(&governance, performed_action_id, ALICE);
+    let action_id = ok(governance.propose_remove_board_member(Address::from(ALICE)));
+    assert!(governance.perform_action(action_id).is_ok());
+    assert!(governance
+        .action_signers(performed_action_id)
+        .contains(&Address::from(ALICE)));
+    assert!(!governance.pending_action_ids().contains(&action_id));
+}
+
+#[test]
+fn change_quorum_above_board_size_test() {
+    let governance = setup(1, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_change_quorum(3));
+
+    assert_err(
+        governance.perform_action(action_id),
+        b"Quorum cannot exceed board size",
+    );
+}
+
+#[test]
+fn add_board_member_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_add_board_member(Address::from(CAROL)));
+    sign_as(&governance, action_id, ALICE);
+
+    assert!(governance.perform_action(action_id).is_ok());
+    assert!(governance.board_members().contains(&Address::from(CAROL)));
+
+    let action_id = ok(governance.propose_add_board_member(Address::from(ALICE)));
+    sign_as(&governance, action_id, ALICE);
+
+    assert_err(
+        governance.perform_action(action_id),
+        b"Address is already a board member",
+    );
+}
+
+#[test]
+fn remove_board_member_test() {
+    let governance = setup(2, &[CALLER, ALICE, BOB]);
+    let pending_action_id = ok(governance.propose_change_quorum(3));
+    sign_as(&governance, pending_action_id, BOB);
+    assert_eq!(governance.get_valid_signer_count(pending_action_id), 2);
+
+    let action_id = ok(governance.propose_remove_board_member(Address::from(BOB)));
+    sign_as(&governance, action_id, ALICE);
+
+    assert!(governance.perform_action(action_id).is_ok());
+    assert!(!governance.board_members().contains(&Address::from(BOB)));
+    assert!(!governance
+        .action_signers(pending_action_id)
+        .contains(&Address::from(BOB)));
+    assert_eq!(governance.get_valid_signer_count(pending_action_id), 1);
+}
+
+#[test]
+fn readded_board_member_signatures_test() {
+    let governance = setup(2, &[CALLER, ALICE, BOB]);
+    let pending_action_id = ok(governance.propose_change_quorum(3));
+    sign_as(&governance, pending_action_id, BOB);
+
+    let action_id = ok(governance.propose_remove_board_member(Address::from(BOB)));
+    sign_as(&governance, action_id, ALICE);
+    assert!(governance.perform_action(action_id).is_ok());
+
+    let action_id = ok(governance.propose_add_board_member(Address::from(BOB)));
+    sign_as(&governance, action_id, ALICE);
+    assert!(governance.perform_action(action_id).is_ok());
+
+    // the signature given before the removal does not count again
+    assert!(governance.board_members().contains(&Address::from(BOB)));
+    assert_eq!(governance.get_valid_signer_count(pending_action_id), 1);
+    assert!(!governance.quorum_reached(pending_action_id));
+}
+
+#[test]
+fn remove_board_member_below_quorum_test() {
+    let governance = setup(2, &[CALLER, ALICE]);
+    let action_id = ok(governance.propose_remove_board_member(Address::from(ALICE)));
+    sign_as(&governance, action_id, ALICE);
+
+    assert_err(
+        governance.perform_action(action_id),
+        b"Board size cannot go below quorum",
+    );
+}
+
+#[test]
+fn propose_sc_call_test() {
+    let governance = setup(1, &[CALLER, ALICE]);
+
+    assert_err(
+        governance.propose_sc_call(
+            Address::from(CAROL),
+            BoxedBytes::empty(),
+            VarArgs::from(Vec::new()),
+        ),
+        b"Endpoint name cannot be empty",
+    );
+
+    let action_id = ok(governance.propose_sc_call(
+        Address::from(CAROL),
+        BoxedBytes::from(&b"setOwnChainId"[..]),
+        VarArgs::from(vec![BoxedBytes::from(&[5u8][..])]),
+    ));
+
+    match ok(governance.get_action_data(action_id)) {
+        Action::SCCall {
+            to,
+            endpoint_name,
+            arguments,
+        } => {
+            assert_eq!(to, Address::from(CAROL));
+            assert_eq!(endpoint_name, BoxedBytes::from(&b"setOwnChainId"[..]));
+            assert_eq!(arguments, vec![BoxedBytes::from(&[5u8][..])]);
+        }
+        _ => panic!("Action should be an SC call"),
+    }
+    assert!(governance.quorum_reached(action_id));
+}
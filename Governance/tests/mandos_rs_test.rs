@@ -0,0 +1,15 @@
+use elrond_wasm_debug::*;
+
+fn contract_map() -> ContractMap<TxContext> {
+    let mut contract_map = ContractMap::new();
+    contract_map.register_contract(
+        "file:../output/governance.wasm",
+        Box::new(|context| Box::new(governance::contract_obj(context))),
+    );
+    contract_map
+}
+
+#[test]
+fn perform_sc_call_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/perform_sc_call.scen.json", &contract_map());
+}
@@ -0,0 +1,52 @@
+use elrond_wasm::api::BigUintApi;
+
+elrond_wasm::derive_imports!();
+
+/// Maximum number of decimals accepted for either side of an asset mapping
+pub const MAX_DECIMALS: u8 = 36;
+
+/// What happens to the part of an amount that cannot be represented with fewer decimals
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum DustPolicy {
+    /// The whole transfer fails
+    Reject,
+    /// On lock, the dust is sent back to the sender. On unlock, it's rounded down.
+    Refund,
+    /// The dust is kept by the bridge
+    RoundDown,
+}
+
+/// Decimals of the Elrond token and of its representation on the other chain
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub struct AssetDecimals {
+    pub local_decimals: u8,
+    pub remote_decimals: u8,
+    pub dust_policy: DustPolicy,
+}
+
+/// Converts the amount between the given number of decimals.
+/// Returns the converted amount, and the dust which could not be converted, in `from_decimals`.
+pub fn scale_amount<BigUint: BigUintApi>(
+    amount: &BigUint,
+    from_decimals: u8,
+    to_decimals: u8,
+) -> (BigUint, BigUint) {
+    if to_decimals >= from_decimals {
+        let factor = pow_10::<BigUint>(to_decimals - from_decimals);
+        return (amount * &factor, BigUint::zero());
+    }
+
+    let factor = pow_10::<BigUint>(from_decimals - to_decimals);
+
+    (amount / &factor, amount % &factor)
+}
+
+fn pow_10<BigUint: BigUintApi>(exponent: u8) -> BigUint {
+    let ten = BigUint::from(10u32);
+    let mut result = BigUint::from(1u32);
+    for _ in 0..exponent {
+        result *= &ten;
+    }
+
+    result
+}
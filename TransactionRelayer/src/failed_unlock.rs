@@ -0,0 +1,13 @@
+use elrond_wasm::types::BoxedBytes;
+
+elrond_wasm::derive_imports!();
+
+/// An inbound transfer which could not be unlocked, kept so it can be retried or refunded
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct FailedUnlock {
+    pub from_chain_id: u64,
+    /// the raw TransactionArgs, or NftTransactionArgs for NFTs/SFTs, as received from the CrossChainManagement SC
+    pub method_args: BoxedBytes,
+    pub reason: BoxedBytes,
+    pub is_nft: bool,
+}
@@ -0,0 +1,175 @@
+use access_control::Role;
+
+elrond_wasm::imports!();
+
+/// Fees are expressed in basis points, i.e. 1/10_000 of the amount
+pub const MAX_FEE_BASIS_POINTS: u32 = 10_000;
+
+/// Per token and destination chain fees, taken from fungible tokens on lock.
+/// The fee is a flat amount, plus a percentage of the bridged amount.
+#[elrond_wasm::module]
+pub trait FeesModule: access_control::AccessControlModule {
+    // endpoints - asset managers
+
+    #[endpoint(setFee)]
+    fn set_fee(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+        flat_fee: Self::BigUint,
+        fee_basis_points: u32,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(
+            fee_basis_points <= MAX_FEE_BASIS_POINTS,
+            "Fee cannot be more than the whole amount"
+        );
+
+        self.flat_fee(&token_id, to_chain_id).set(&flat_fee);
+        self.fee_basis_points(&token_id, to_chain_id)
+            .set(&fee_basis_points);
+
+        Ok(())
+    }
+
+    // endpoints - fee collectors
+
+    /// Sends all the collected fees of the token to the caller, or to `opt_to`
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(
+        &self,
+        token_id: TokenIdentifier,
+        #[var_args] opt_to: OptionalArg<Address>,
+    ) -> SCResult<Self::BigUint> {
+        self.require_role(Role::FeeCollector)?;
+
+        let amount = self.accumulated_fees(&token_id).get();
+        require!(amount > 0, "No fees to withdraw");
+
+        let to = match opt_to {
+            OptionalArg::Some(to) => to,
+            OptionalArg::None => self.blockchain().get_caller(),
+        };
+
+        self.accumulated_fees(&token_id).clear();
+        self.send()
+            .direct(&to, &token_id, 0, &amount, b"PolyBridge fees");
+
+        self.fees_withdrawn_event(&token_id, &to, &amount);
+
+        Ok(amount)
+    }
+
+    // views
+
+    #[view(getFee)]
+    fn get_fee(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+    ) -> MultiResult2<Self::BigUint, u32> {
+        (
+            self.flat_fee(&token_id, to_chain_id).get(),
+            self.fee_basis_points(&token_id, to_chain_id).get(),
+        )
+            .into()
+    }
+
+    /// The fee taken when bridging the given amount
+    #[view(getBridgeFee)]
+    fn get_bridge_fee(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+        amount: Self::BigUint,
+    ) -> Self::BigUint {
+        self.compute_fee(&token_id, to_chain_id, &amount)
+    }
+
+    #[view(getAccumulatedFees)]
+    fn get_accumulated_fees(&self, token_id: TokenIdentifier) -> Self::BigUint {
+        self.accumulated_fees(&token_id).get()
+    }
+
+    // private
+
+    fn compute_fee(
+        &self,
+        token_id: &TokenIdentifier,
+        to_chain_id: u64,
+        amount: &Self::BigUint,
+    ) -> Self::BigUint {
+        let flat_fee = self.flat_fee(token_id, to_chain_id).get();
+        let fee_basis_points = self.fee_basis_points(token_id, to_chain_id).get();
+        if fee_basis_points == 0 {
+            return flat_fee;
+        }
+
+        let percentage_fee = amount * &Self::BigUint::from(fee_basis_points as u64)
+            / Self::BigUint::from(MAX_FEE_BASIS_POINTS as u64);
+
+        flat_fee + percentage_fee
+    }
+
+    /// Deducts the fee from the amount, and keeps it for the fee collectors
+    fn take_fee(
+        &self,
+        token_id: &TokenIdentifier,
+        to_chain_id: u64,
+        amount: &Self::BigUint,
+    ) -> SCResult<Self::BigUint> {
+        let fee = self.compute_fee(token_id, to_chain_id, amount);
+        if fee == 0 {
+            return Ok(amount.clone());
+        }
+
+        require!(amount > &fee, "Amount does not cover the bridge fee");
+
+        self.accumulated_fees(token_id)
+            .update(|accumulated| *accumulated += &fee);
+        self.fee_collected_event(token_id, to_chain_id, &fee);
+
+        Ok(amount - &fee)
+    }
+
+    // events
+
+    #[event("fee_collected_event")]
+    fn fee_collected_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] to_chain_id: u64,
+        fee: &Self::BigUint,
+    );
+
+    #[event("fees_withdrawn_event")]
+    fn fees_withdrawn_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] to: &Address,
+        amount: &Self::BigUint,
+    );
+
+    // storage
+
+    #[storage_mapper("flatFee")]
+    fn flat_fee(
+        &self,
+        token_id: &TokenIdentifier,
+        to_chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[storage_mapper("feeBasisPoints")]
+    fn fee_basis_points(
+        &self,
+        token_id: &TokenIdentifier,
+        to_chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, u32>;
+
+    /// Fees collected and not yet withdrawn, by token. Not part of the escrowed balance.
+    #[storage_mapper("accumulatedFees")]
+    fn accumulated_fees(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+}
@@ -1,14 +1,40 @@
 #![no_std]
 
-use transaction::TransactionArgs;
+use access_control::Role;
+use elrond_wasm::elrond_codec::TopEncode;
+use limits::TransferDirection;
+use transaction::{NftTransactionArgs, TransactionArgs, TransactionStatus};
 
 elrond_wasm::imports!();
 
+pub mod decimals;
+mod failed_unlock;
+pub mod fees;
+pub mod limits;
+mod token_mode;
+
+pub use decimals::{AssetDecimals, DustPolicy};
+pub use failed_unlock::FailedUnlock;
+pub use token_mode::TokenMode;
+
 pub const UNLOCK_METHOD_NAME: &[u8] = b"unlock";
+pub const UNLOCK_NFT_METHOD_NAME: &[u8] = b"unlockNft";
 
-mod cross_chain_management_proxy {
-    use transaction::TransactionArgs;
+/// Endpoint called on bridge receiver contracts, together with the transfer, with the arguments:
+/// from_chain_id: u64, from_address: BoxedBytes, data: BoxedBytes
+pub const BRIDGE_RECEIVE_ENDPOINT_NAME: &[u8] = b"onBridgeReceive";
+
+/// Maximum number of tokens bridged through a single lockMulti call
+pub const MAX_LOCK_MULTI_TRANSFERS: usize = 10;
 
+/// Version of the storage layout. Bump when a release changes the layout,
+/// and add the corresponding step in `migrate_storage_from`.
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+
+/// Gas kept by the relayer when calling a receiver, so a failed call can still be recorded
+const BRIDGE_RECEIVE_GAS_RESERVE: u64 = 5_000_000;
+
+mod cross_chain_management_proxy {
     elrond_wasm::imports!();
 
     #[elrond_wasm::proxy]
@@ -20,46 +46,1245 @@ mod cross_chain_management_proxy {
             to_chain_id: u64,
             to_contract_address: BoxedBytes,
             method_name: BoxedBytes,
-            method_args: TransactionArgs<Self::BigUint>,
+            method_args: BoxedBytes,
         );
+
+        #[endpoint(reportFailedTxExecuted)]
+        fn report_failed_tx_executed(&self, failed_tx_id: u64);
     }
 }
 
-#[elrond_wasm::contract]
-pub trait TransactionRelayer {
-    #[init]
-    fn init(&self) {}
+#[elrond_wasm::contract]
+pub trait TransactionRelayer:
+    access_control::AccessControlModule + fees::FeesModule + limits::LimitsModule + pause::PauseModule
+{
+    /// Also called on upgrade, so it only runs the pending storage migrations.
+    /// The legacy chain and token registries cannot be enumerated, so upgrades from them
+    /// list their (chain ID, token ID) pairs, to be copied into the current registries.
+    #[init]
+    fn init(
+        &self,
+        #[var_args] legacy_assets: VarArgs<MultiArg2<u64, TokenIdentifier>>,
+    ) -> SCResult<()> {
+        let legacy_assets: Vec<(u64, TokenIdentifier)> = legacy_assets
+            .into_vec()
+            .into_iter()
+            .map(|legacy_asset| legacy_asset.into_tuple())
+            .collect();
+
+        self.migrate_storage(&legacy_assets)
+    }
+
+    // endpoints - owner-only
+
+    /// Runs the pending storage migrations, in case they were not run on upgrade
+    #[endpoint(migrateStorage)]
+    fn migrate_storage_endpoint(&self) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        self.migrate_storage(&[])
+    }
+
+    /// Copies (chain ID, token ID) pairs of the legacy registries which were not listed on upgrade
+    #[endpoint(migrateLegacyAssets)]
+    fn migrate_legacy_assets_endpoint(
+        &self,
+        #[var_args] legacy_assets: VarArgs<MultiArg2<u64, TokenIdentifier>>,
+    ) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+
+        for legacy_asset in legacy_assets.into_vec() {
+            let (chain_id, token_id) = legacy_asset.into_tuple();
+            self.migrate_legacy_asset(chain_id, &token_id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Each asset hash can only represent one token on a given chain
+    #[endpoint(setAssetHash)]
+    fn set_asset_hash(
+        &self,
+        token_id: TokenIdentifier,
+        to_chain_id: u64,
+        other_chain_asset_hash: BoxedBytes,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(
+            self.supported_chains().contains_key(&to_chain_id),
+            "Chain not supported"
+        );
+        require!(
+            !other_chain_asset_hash.is_empty(),
+            "Asset hash cannot be empty"
+        );
+
+        let mut asset_tokens = self.chain_asset_tokens(to_chain_id);
+        if let Some(registered_token_id) = asset_tokens.get(&other_chain_asset_hash) {
+            require!(
+                registered_token_id == token_id,
+                "Asset hash already registered for another token"
+            );
+        }
+
+        if let Some(previous_asset_hash) = self
+            .chain_assets(to_chain_id)
+            .insert(token_id.clone(), other_chain_asset_hash.clone())
+        {
+            asset_tokens.remove(&previous_asset_hash);
+        }
+        asset_tokens.insert(other_chain_asset_hash, token_id);
+
+        Ok(())
+    }
+
+    #[endpoint(removeAssetHash)]
+    fn remove_asset_hash(&self, token_id: TokenIdentifier, to_chain_id: u64) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        let asset_hash = self
+            .chain_assets(to_chain_id)
+            .remove(&token_id)
+            .ok_or("Token not registered for the chain")?;
+        self.chain_asset_tokens(to_chain_id).remove(&asset_hash);
+
+        Ok(())
+    }
+
+    #[endpoint(setChainProxy)]
+    fn set_chain_proxy_hash(&self, chain_id: u64, proxy_hash: BoxedBytes) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(!proxy_hash.is_empty(), "Proxy hash cannot be empty");
+
+        self.supported_chains().insert(chain_id, proxy_hash);
+
+        Ok(())
+    }
+
+    /// The chain's tokens have to be removed first
+    #[endpoint(removeChain)]
+    fn remove_chain(&self, chain_id: u64) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(
+            self.chain_assets(chain_id).is_empty(),
+            "Chain still has registered tokens"
+        );
+        require!(
+            self.supported_chains().remove(&chain_id).is_some(),
+            "Chain not supported"
+        );
+
+        Ok(())
+    }
+
+    /// Amounts are only scaled for mappings with decimals set
+    #[endpoint(setAssetDecimals)]
+    fn set_asset_decimals(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        local_decimals: u8,
+        remote_decimals: u8,
+        dust_policy: DustPolicy,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(
+            local_decimals <= decimals::MAX_DECIMALS && remote_decimals <= decimals::MAX_DECIMALS,
+            "Too many decimals"
+        );
+
+        self.asset_decimals(&token_id, chain_id)
+            .set(&AssetDecimals {
+                local_decimals,
+                remote_decimals,
+                dust_policy,
+            });
+
+        Ok(())
+    }
+
+    /// Allows the contract to receive bridged tokens through its onBridgeReceive endpoint.
+    /// Only contracts in the relayer's shard can be registered, as a failed call to a contract
+    /// in another shard cannot be detected, and its tokens could not be claimed.
+    #[endpoint(registerBridgeReceiver)]
+    fn register_bridge_receiver(&self, receiver_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.blockchain().is_smart_contract(&receiver_address),
+            "Receiver address is not a smart contract address"
+        );
+        require!(
+            self.is_in_relayer_shard(&receiver_address),
+            "Receiver must be in the same shard as the relayer"
+        );
+        require!(
+            self.bridge_receivers().insert(receiver_address),
+            "Receiver already registered"
+        );
+
+        Ok(())
+    }
+
+    #[endpoint(unregisterBridgeReceiver)]
+    fn unregister_bridge_receiver(&self, receiver_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.bridge_receivers().remove(&receiver_address),
+            "Receiver not registered"
+        );
+
+        Ok(())
+    }
+
+    /// Tokens in custody mode can only be switched back once nothing is escrowed anymore
+    #[endpoint(setTokenMode)]
+    fn set_token_mode(&self, token_id: TokenIdentifier, mode: TokenMode) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(token_id.is_esdt(), "Invalid token ID");
+        require!(
+            !self.is_wrapped_egld(&token_id),
+            "Wrapped eGLD is always held in custody"
+        );
+        require!(
+            mode == TokenMode::Custody || self.escrowed_balance(&token_id).is_empty(),
+            "Cannot leave custody mode while tokens are escrowed"
+        );
+
+        self.token_mode(&token_id).set(&mode);
+
+        Ok(())
+    }
+
+    /// Sends the dust kept from custody locks to the caller, or to `opt_to`
+    #[endpoint(withdrawRoundedDownDust)]
+    fn withdraw_rounded_down_dust(
+        &self,
+        token_id: TokenIdentifier,
+        #[var_args] opt_to: OptionalArg<Address>,
+    ) -> SCResult<Self::BigUint> {
+        self.require_role(Role::FeeCollector)?;
+
+        let amount = self.rounded_down_dust(&token_id).get();
+        require!(amount > 0, "No dust to withdraw");
+
+        let to = match opt_to {
+            OptionalArg::Some(to) => to,
+            OptionalArg::None => self.blockchain().get_caller(),
+        };
+
+        self.rounded_down_dust(&token_id).clear();
+        self.send()
+            .direct(&to, &token_id, 0, &amount, b"PolyBridge dust");
+
+        Ok(amount)
+    }
+
+    /// Sets the EsdtTokenManager used for eGLD transfers, and reads its wrapped eGLD token ID
+    #[endpoint(setEgldTokenManager)]
+    fn set_egld_token_manager(&self, token_manager_address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.blockchain().is_smart_contract(&token_manager_address),
+            "Invalid EsdtTokenManager address"
+        );
+
+        let wrapped_egld_token_id = self
+            .esdt_token_manager_proxy(token_manager_address.clone())
+            .get_wrapped_egld_token_id()
+            .execute_on_dest_context();
+        require!(
+            wrapped_egld_token_id.is_valid_esdt_identifier(),
+            "Wrapped eGLD not issued in the EsdtTokenManager"
+        );
+
+        self.egld_token_manager_address()
+            .set(&token_manager_address);
+        self.wrapped_egld_token_id().set(&wrapped_egld_token_id);
+
+        Ok(())
+    }
+
+    /// Called by the CrossChainManagement SC for inbound transactions, with the raw TransactionArgs.
+    /// Transfers which cannot be unlocked are recorded as failed instead of reverting,
+    /// so they can be retried or refunded later. Returns Failed in that case, so the tx is marked as such,
+    /// along with the failed unlock's ID, which is 0 for executed transfers.
+    #[only_owner]
+    #[endpoint]
+    fn unlock(
+        &self,
+        method_args: BoxedBytes,
+        from_contract_address: BoxedBytes,
+        from_chain_id: u64,
+    ) -> SCResult<MultiResult2<TransactionStatus, u64>> {
+        self.require_inbound_not_paused()?;
+        self.require_from_proxy_contract(&from_contract_address, from_chain_id)?;
+
+        Ok(self.unlock_or_record_failure(method_args, from_chain_id, false))
+    }
+
+    /// Called by the CrossChainManagement SC for inbound NFT/SFT transactions, with the raw NftTransactionArgs.
+    /// Tokens held by the relayer are released first. Otherwise, tokens that were never bridged are created,
+    /// and more of an already known SFT is minted. Custody tokens can only be released.
+    /// Failures are recorded the same way as for `unlock`.
+    #[only_owner]
+    #[endpoint(unlockNft)]
+    fn unlock_nft(
+        &self,
+        method_args: BoxedBytes,
+        from_contract_address: BoxedBytes,
+        from_chain_id: u64,
+    ) -> SCResult<MultiResult2<TransactionStatus, u64>> {
+        self.require_inbound_not_paused()?;
+        self.require_from_proxy_contract(&from_contract_address, from_chain_id)?;
+
+        Ok(self.unlock_or_record_failure(method_args, from_chain_id, true))
+    }
+
+    /// Anyone can retry a failed unlock, once the cause of the failure is fixed.
+    /// The CrossChainManagement SC is notified, so the tx is marked as Executed.
+    #[endpoint(retryFailedUnlock)]
+    fn retry_failed_unlock(&self, failed_unlock_id: u64) -> SCResult<()> {
+        self.require_inbound_not_paused()?;
+
+        let failed_unlock = self.try_take_failed_unlock(failed_unlock_id)?;
+        if failed_unlock.is_nft {
+            self.try_unlock_nft(&failed_unlock.method_args, failed_unlock.from_chain_id)?;
+        } else {
+            self.try_unlock(&failed_unlock.method_args, failed_unlock.from_chain_id)?;
+        }
+
+        self.failed_unlock_resolved_event(failed_unlock_id, false);
+
+        let ccm_address = self.get_cross_chain_management_sc_address();
+        self.cross_chain_management_proxy(ccm_address)
+            .report_failed_tx_executed(failed_unlock_id)
+            .execute_on_dest_context();
+
+        Ok(())
+    }
+
+    /// Sends the tokens back to the sender on the source chain, through a new outbound transaction.
+    /// Only possible if the source chain provided the sender's address.
+    /// The inbound tx stays Failed in the CrossChainManagement SC, the refund being a tx of its own.
+    #[endpoint(refundFailedUnlock)]
+    fn refund_failed_unlock(&self, failed_unlock_id: u64) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        self.require_outbound_not_paused()?;
+
+        let failed_unlock = self.try_take_failed_unlock(failed_unlock_id)?;
+        if failed_unlock.is_nft {
+            self.refund_nft_unlock(&failed_unlock)?;
+        } else {
+            self.refund_unlock(&failed_unlock)?;
+        }
+
+        self.failed_unlock_resolved_event(failed_unlock_id, true);
+
+        Ok(())
+    }
+
+    // endpoints
+
+    #[payable("*")]
+    #[endpoint]
+    fn lock(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_amount] payment_amount: Self::BigUint,
+        to_chain_id: u64,
+        dest_address: BoxedBytes,
+    ) -> SCResult<()> {
+        self.require_outbound_not_paused()?;
+
+        let nonce = self.call_value().esdt_token_nonce();
+        self.lock_payment(
+            payment_token,
+            nonce,
+            payment_amount,
+            to_chain_id,
+            dest_address,
+        )
+    }
+
+    /// Locks all the ESDTs of a multi-transfer, creating one outbound transaction for each of them
+    #[payable("*")]
+    #[endpoint(lockMulti)]
+    fn lock_multi(&self, to_chain_id: u64, dest_address: BoxedBytes) -> SCResult<()> {
+        self.require_outbound_not_paused()?;
+
+        let payments = self.call_value().get_all_esdt_transfers();
+        self.require_lock_multi_transfer_count(payments.len())?;
+
+        for payment in payments {
+            self.lock_payment(
+                payment.token_name,
+                payment.token_nonce,
+                payment.amount,
+                to_chain_id,
+                dest_address.clone(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Wraps the eGLD through the EsdtTokenManager, and locks the wrapped eGLD
+    #[payable("EGLD")]
+    #[endpoint(lockEgld)]
+    fn lock_egld(
+        &self,
+        #[payment] payment_amount: Self::BigUint,
+        to_chain_id: u64,
+        dest_address: BoxedBytes,
+    ) -> SCResult<()> {
+        self.require_outbound_not_paused()?;
+        self.require_egld_token_manager_set()?;
+        require!(payment_amount > 0, "Must bridge more than 0 tokens");
+
+        let token_manager_address = self.egld_token_manager_address().get();
+        self.esdt_token_manager_proxy(token_manager_address)
+            .wrap_egld(payment_amount.clone())
+            .execute_on_dest_context();
+
+        let wrapped_egld_token_id = self.wrapped_egld_token_id().get();
+        self.lock_tokens(
+            wrapped_egld_token_id,
+            payment_amount,
+            to_chain_id,
+            dest_address,
+        )
+    }
+
+    /// Claims tokens whose delivery to the calling bridge receiver contract failed
+    #[endpoint(claimBridgedTokens)]
+    fn claim_bridged_tokens(&self, token_id: TokenIdentifier) -> SCResult<Self::BigUint> {
+        let caller = self.blockchain().get_caller();
+        let claimable_mapper = self.claimable_balance(&caller, &token_id);
+        let amount = claimable_mapper.get();
+        require!(amount > 0, "Nothing to claim");
+
+        claimable_mapper.clear();
+        self.send()
+            .direct(&caller, &token_id, 0, &amount, b"PolyBridge claim");
+
+        Ok(amount)
+    }
+
+    // views
+
+    #[view(getSupportedChains)]
+    fn get_supported_chains(&self) -> MultiResultVec<u64> {
+        self.supported_chains().keys().collect::<Vec<u64>>().into()
+    }
+
+    #[view(getChainProxy)]
+    fn get_chain_proxy_hash(&self, chain_id: u64) -> SCResult<BoxedBytes> {
+        let proxy_hash = self
+            .supported_chains()
+            .get(&chain_id)
+            .ok_or("Chain not supported")?;
+
+        Ok(proxy_hash)
+    }
+
+    /// Pairs of chain ID and the chain's proxy contract
+    #[view(getChainProxies)]
+    fn get_chain_proxies(&self) -> MultiResultVec<MultiResult2<u64, BoxedBytes>> {
+        self.supported_chains()
+            .iter()
+            .map(|(chain_id, proxy_hash)| (chain_id, proxy_hash).into())
+            .collect::<Vec<MultiResult2<u64, BoxedBytes>>>()
+            .into()
+    }
+
+    /// Pairs of Elrond token ID and the chain's asset hash
+    #[view(getSupportedTokens)]
+    fn get_supported_tokens(
+        &self,
+        chain_id: u64,
+    ) -> MultiResultVec<MultiResult2<TokenIdentifier, BoxedBytes>> {
+        self.chain_assets(chain_id)
+            .iter()
+            .map(|(token_id, asset_hash)| (token_id, asset_hash).into())
+            .collect::<Vec<MultiResult2<TokenIdentifier, BoxedBytes>>>()
+            .into()
+    }
+
+    #[view(getAssetHash)]
+    fn get_token_asset_hash(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+    ) -> SCResult<BoxedBytes> {
+        let asset_hash = self
+            .chain_assets(chain_id)
+            .get(&token_id)
+            .ok_or("Token not registered for the chain")?;
+
+        Ok(asset_hash)
+    }
+
+    #[view(getTokenForAssetHash)]
+    fn get_token_for_asset_hash(
+        &self,
+        chain_id: u64,
+        asset_hash: BoxedBytes,
+    ) -> SCResult<TokenIdentifier> {
+        let token_id = self
+            .chain_asset_tokens(chain_id)
+            .get(&asset_hash)
+            .ok_or("Asset hash not registered for the chain")?;
+
+        Ok(token_id)
+    }
+
+    #[view(getFailedUnlockIds)]
+    fn get_failed_unlock_ids(&self) -> MultiResultVec<u64> {
+        self.failed_unlock_ids().iter().collect::<Vec<u64>>().into()
+    }
+
+    #[view(getFailedUnlock)]
+    fn get_failed_unlock(&self, failed_unlock_id: u64) -> SCResult<FailedUnlock> {
+        require!(
+            self.failed_unlock_ids().contains(&failed_unlock_id),
+            "Failed unlock not found"
+        );
+
+        Ok(self.failed_unlock(failed_unlock_id).get())
+    }
+
+    #[view(getBridgeReceivers)]
+    fn get_bridge_receivers(&self) -> MultiResultVec<Address> {
+        self.bridge_receivers()
+            .iter()
+            .collect::<Vec<Address>>()
+            .into()
+    }
+
+    #[view(getClaimableBalance)]
+    fn get_claimable_balance(
+        &self,
+        receiver_address: Address,
+        token_id: TokenIdentifier,
+    ) -> Self::BigUint {
+        self.claimable_balance(&receiver_address, &token_id).get()
+    }
+
+    #[view(getTokenMode)]
+    fn get_token_mode(&self, token_id: TokenIdentifier) -> TokenMode {
+        self.token_mode(&token_id).get()
+    }
+
+    #[view(getAssetDecimals)]
+    fn get_asset_decimals(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+    ) -> SCResult<AssetDecimals> {
+        let decimals_mapper = self.asset_decimals(&token_id, chain_id);
+        require!(!decimals_mapper.is_empty(), "Decimals not set for asset");
+
+        Ok(decimals_mapper.get())
+    }
+
+    #[view(getEscrowedBalance)]
+    fn get_escrowed_balance(&self, token_id: TokenIdentifier) -> Self::BigUint {
+        self.escrowed_balance(&token_id).get()
+    }
+
+    #[view(getRoundedDownDust)]
+    fn get_rounded_down_dust(&self, token_id: TokenIdentifier) -> Self::BigUint {
+        self.rounded_down_dust(&token_id).get()
+    }
+
+    #[view(getEscrowedNftBalance)]
+    fn get_escrowed_nft_balance(&self, token_id: TokenIdentifier, nonce: u64) -> Self::BigUint {
+        self.escrowed_nft_balance(&token_id, nonce).get()
+    }
+
+    /// Returns 0 if the foreign token ID was never bridged
+    #[view(getNftNonce)]
+    fn get_nft_nonce(&self, token_id: TokenIdentifier, foreign_token_id: Self::BigUint) -> u64 {
+        self.nft_nonce(&token_id, &foreign_token_id).get()
+    }
+
+    #[view(getNftForeignTokenId)]
+    fn get_nft_foreign_token_id(
+        &self,
+        token_id: TokenIdentifier,
+        nonce: u64,
+    ) -> SCResult<Self::BigUint> {
+        let foreign_id_mapper = self.nft_foreign_token_id(&token_id, nonce);
+        require!(!foreign_id_mapper.is_empty(), "Token was never bridged");
+
+        Ok(foreign_id_mapper.get())
+    }
+
+    // private
+
+    fn migrate_storage(&self, legacy_assets: &[(u64, TokenIdentifier)]) -> SCResult<()> {
+        let initial_version = self.storage_version().get();
+        require!(
+            initial_version <= CURRENT_STORAGE_VERSION,
+            "Storage version is newer than the contract code"
+        );
+
+        let mut version = initial_version;
+        while version < CURRENT_STORAGE_VERSION {
+            self.migrate_storage_from(version, legacy_assets)?;
+            version += 1;
+        }
+
+        if initial_version != CURRENT_STORAGE_VERSION {
+            self.storage_version().set(&CURRENT_STORAGE_VERSION);
+            self.storage_migrated_event(initial_version, CURRENT_STORAGE_VERSION);
+        }
+
+        Ok(())
+    }
+
+    /// Migrates the storage from the given version to the next one
+    fn migrate_storage_from(
+        &self,
+        version: u32,
+        legacy_assets: &[(u64, TokenIdentifier)],
+    ) -> SCResult<()> {
+        match version {
+            // wrapped eGLD used to be kept on lock without being tracked as escrowed
+            0 => {
+                if !self.wrapped_egld_token_id().is_empty() {
+                    let token_id = self.wrapped_egld_token_id().get();
+                    let held_amount = self.blockchain().get_sc_balance(&token_id, 0);
+                    self.seed_escrowed_balance(&token_id, &held_amount);
+                }
+
+                Ok(())
+            }
+            // chains and tokens used to be kept in plain keys, which cannot be enumerated
+            1 => {
+                for (chain_id, token_id) in legacy_assets {
+                    self.migrate_legacy_asset(*chain_id, token_id)?;
+                }
+
+                Ok(())
+            }
+            _ => sc_error!("No migration defined for storage version"),
+        }
+    }
+
+    /// Copies the chain's legacy proxy hash, if not copied yet, and the token's legacy asset hash.
+    /// Chains without any token cannot be used, so they are simply set again.
+    fn migrate_legacy_asset(&self, chain_id: u64, token_id: &TokenIdentifier) -> SCResult<()> {
+        let legacy_proxy_hash_mapper = self.legacy_proxy_hash_map(chain_id);
+        if !legacy_proxy_hash_mapper.is_empty() {
+            self.supported_chains()
+                .insert(chain_id, legacy_proxy_hash_mapper.get());
+            legacy_proxy_hash_mapper.clear();
+        }
+        require!(
+            self.supported_chains().contains_key(&chain_id),
+            "Chain not supported"
+        );
+
+        let legacy_asset_hash_mapper = self.legacy_asset_hash_map(token_id, chain_id);
+        require!(
+            !legacy_asset_hash_mapper.is_empty(),
+            "No legacy asset hash for the token"
+        );
+
+        let asset_hash = legacy_asset_hash_mapper.get();
+        let mut asset_tokens = self.chain_asset_tokens(chain_id);
+        require!(
+            !asset_tokens.contains_key(&asset_hash),
+            "Asset hash already registered for another token"
+        );
+
+        self.chain_assets(chain_id)
+            .insert(token_id.clone(), asset_hash.clone());
+        asset_tokens.insert(asset_hash, token_id.clone());
+        legacy_asset_hash_mapper.clear();
+
+        Ok(())
+    }
+
+    /// Everything held by the relayer, except the collected fees, backs tokens on other chains
+    fn seed_escrowed_balance(&self, token_id: &TokenIdentifier, held_amount: &Self::BigUint) {
+        let escrowed_balance_mapper = self.escrowed_balance(token_id);
+        if !escrowed_balance_mapper.is_empty() {
+            return;
+        }
+
+        let fees = self.accumulated_fees(token_id).get();
+        if held_amount > &fees {
+            escrowed_balance_mapper.set(&(held_amount - &fees));
+        }
+    }
+
+    fn lock_payment(
+        &self,
+        payment_token: TokenIdentifier,
+        nonce: u64,
+        payment_amount: Self::BigUint,
+        to_chain_id: u64,
+        dest_address: BoxedBytes,
+    ) -> SCResult<()> {
+        require!(
+            payment_token.is_esdt(),
+            "eGLD payment not allowed, use lockEgld instead"
+        );
+
+        if nonce != 0 {
+            return self.lock_nft(
+                payment_token,
+                nonce,
+                payment_amount,
+                to_chain_id,
+                dest_address,
+            );
+        }
+
+        self.lock_tokens(payment_token, payment_amount, to_chain_id, dest_address)
+    }
+
+    /// The bridge fee is taken first, and kept by the relayer for the fee collectors.
+    /// Tokens in custody are kept by the relayer. This includes wrapped eGLD,
+    /// which is backed by the eGLD in the EsdtTokenManager. All other tokens are burned.
+    fn lock_tokens(
+        &self,
+        payment_token: TokenIdentifier,
+        payment_amount: Self::BigUint,
+        to_chain_id: u64,
+        dest_address: BoxedBytes,
+    ) -> SCResult<()> {
+        require!(payment_amount > 0, "Must bridge more than 0 tokens");
+
+        let dest_chain_proxy = self.get_chain_proxy(to_chain_id);
+        require!(
+            !dest_chain_proxy.is_empty(),
+            "Selected Chain ID not supported"
+        );
+
+        let to_asset_hash = self.get_asset_hash(&payment_token, to_chain_id);
+        require!(
+            !to_asset_hash.is_empty(),
+            "This specific token cannot be bridged"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let (remote_amount, locked_amount) =
+            self.take_locked_tokens(&caller, &payment_token, to_chain_id, &payment_amount)?;
+
+        let tx_args = TransactionArgs {
+            dest_address,
+            asset_hash: to_asset_hash,
+            amount: remote_amount,
+            from_address: BoxedBytes::empty(),
+            data: BoxedBytes::empty(),
+        };
+
+        self.create_unlock_tx(to_chain_id, &tx_args)?;
+
+        self.lock_event(
+            &payment_token,
+            &caller,
+            to_chain_id,
+            &tx_args.asset_hash,
+            &tx_args.dest_address,
+            &locked_amount,
+        );
+
+        Ok(())
+    }
+
+    fn require_lock_multi_transfer_count(&self, transfer_count: usize) -> SCResult<()> {
+        require!(transfer_count > 0, "No tokens provided");
+        require!(
+            transfer_count <= MAX_LOCK_MULTI_TRANSFERS,
+            "Too many tokens in a single transfer"
+        );
+
+        Ok(())
+    }
+
+    /// Takes the fee, checks the limits, then escrows or burns the locked tokens.
+    /// Returns the amount to be unlocked on the other chain, and the locked amount.
+    fn take_locked_tokens(
+        &self,
+        caller: &Address,
+        payment_token: &TokenIdentifier,
+        to_chain_id: u64,
+        payment_amount: &Self::BigUint,
+    ) -> SCResult<(Self::BigUint, Self::BigUint)> {
+        let bridged_amount = self.take_fee(payment_token, to_chain_id, payment_amount)?;
+        let (remote_amount, refund_amount, kept_dust) =
+            self.to_remote_amount(payment_token, to_chain_id, &bridged_amount)?;
+        require!(remote_amount > 0, "Amount too small to bridge");
+
+        let locked_amount = &bridged_amount - &refund_amount;
+        self.check_transfer_limits(
+            payment_token,
+            to_chain_id,
+            TransferDirection::Outbound,
+            &locked_amount,
+        )?;
+        self.record_transfer_volume(
+            payment_token,
+            to_chain_id,
+            TransferDirection::Outbound,
+            &locked_amount,
+        );
+
+        if refund_amount > 0 {
+            self.refund_dust(caller, payment_token, &refund_amount);
+        }
+
+        if self.is_custody_token(payment_token) {
+            // the kept dust does not back anything on the other chain, so it's never released
+            let escrowed_amount = &locked_amount - &kept_dust;
+            self.escrowed_balance(payment_token)
+                .update(|balance| *balance += &escrowed_amount);
+            if kept_dust > 0 {
+                self.rounded_down_dust(payment_token)
+                    .update(|dust| *dust += &kept_dust);
+            }
+        } else {
+            self.try_burn(payment_token, &locked_amount)?;
+        }
+
+        Ok((remote_amount, locked_amount))
+    }
+
+    fn try_unlock(&self, method_args: &BoxedBytes, from_chain_id: u64) -> SCResult<()> {
+        let args = TransactionArgs::<Self::BigUint>::from_raw_bytes(method_args.as_slice())?;
+
+        let (token_id, elrond_dest_address) =
+            self.try_get_unlock_destination(&args.asset_hash, &args.dest_address)?;
+        self.require_token_registered(&token_id, from_chain_id)?;
+
+        let is_receiver_contract = self.blockchain().is_smart_contract(&elrond_dest_address);
+        require!(
+            !is_receiver_contract || self.bridge_receivers().contains(&elrond_dest_address),
+            "cannot transfer to smart contract that is not a registered bridge receiver"
+        );
+        require!(
+            !is_receiver_contract || self.is_in_relayer_shard(&elrond_dest_address),
+            "Receiver must be in the same shard as the relayer"
+        );
+
+        let amount = self.to_local_amount(&token_id, from_chain_id, &args.amount)?;
+        require!(amount > 0, "Amount too small to unlock");
+        self.check_transfer_limits(
+            &token_id,
+            from_chain_id,
+            TransferDirection::Inbound,
+            &amount,
+        )?;
+
+        let unwraps_egld = !is_receiver_contract && self.is_wrapped_egld(&token_id);
+        if unwraps_egld {
+            self.require_egld_unwrappable(&amount)?;
+        }
+
+        // failed unlocks are recorded instead of reverting, so nothing may fail after this
+        if self.is_custody_token(&token_id) {
+            self.release_from_escrow(&token_id, &amount)?;
+        } else {
+            self.try_mint(&token_id, &amount)?;
+        }
+        self.record_transfer_volume(
+            &token_id,
+            from_chain_id,
+            TransferDirection::Inbound,
+            &amount,
+        );
+
+        if is_receiver_contract {
+            // receivers always get the ESDT, wrapped eGLD included
+            self.deliver_to_receiver(
+                &elrond_dest_address,
+                &token_id,
+                &amount,
+                from_chain_id,
+                &args.from_address,
+                &args.data,
+            );
+        } else if unwraps_egld {
+            // the wrapped eGLD was kept on lock, so it's only unwrapped, straight to the receiver
+            let token_manager_address = self.egld_token_manager_address().get();
+            self.esdt_token_manager_proxy(token_manager_address)
+                .unwrap_egld(
+                    token_id.clone(),
+                    amount.clone(),
+                    OptionalArg::Some(elrond_dest_address.clone()),
+                )
+                .execute_on_dest_context();
+        } else {
+            self.send().direct(
+                &elrond_dest_address,
+                &token_id,
+                0,
+                &amount,
+                b"PolyBridge Transfer",
+            );
+        }
+
+        self.unlock_event(&token_id, &elrond_dest_address, &amount);
+
+        Ok(())
+    }
+
+    fn try_unlock_nft(&self, method_args: &BoxedBytes, from_chain_id: u64) -> SCResult<()> {
+        let args = NftTransactionArgs::<Self::BigUint>::from_raw_bytes(method_args.as_slice())?;
+
+        let (token_id, elrond_dest_address) =
+            self.try_get_unlock_destination(&args.asset_hash, &args.dest_address)?;
+        self.require_token_registered(&token_id, from_chain_id)?;
+        require!(
+            !self.blockchain().is_smart_contract(&elrond_dest_address),
+            "cannot transfer to smart contract"
+        );
+        require!(args.amount > 0, "Must unlock more than 0 tokens");
+
+        let nonce = match self.try_release_unlocked_nft(&token_id, &args.token_id, &args.amount)? {
+            Some(nonce) => nonce,
+            None => self.mint_unlocked_nft(&token_id, &args)?,
+        };
+
+        self.send().direct(
+            &elrond_dest_address,
+            &token_id,
+            nonce,
+            &args.amount,
+            b"PolyBridge Transfer",
+        );
+
+        self.unlock_nft_event(&token_id, &elrond_dest_address, nonce, &args.amount);
+
+        Ok(())
+    }
+
+    /// Releases the NFTs from escrow if the relayer holds enough of them, or if the collection is in custody.
+    /// Returns the released nonce, or None if the NFTs have to be minted or created.
+    fn try_release_unlocked_nft(
+        &self,
+        token_id: &TokenIdentifier,
+        foreign_token_id: &Self::BigUint,
+        amount: &Self::BigUint,
+    ) -> SCResult<Option<u64>> {
+        let nonce_mapper = self.nft_nonce(token_id, foreign_token_id);
+        if nonce_mapper.is_empty() {
+            require!(
+                !self.is_custody_token(token_id),
+                "Unknown token for a custody collection"
+            );
+
+            return Ok(None);
+        }
+
+        let nonce = nonce_mapper.get();
+        if &self.escrowed_nft_balance(token_id, nonce).get() >= amount
+            || self.is_custody_token(token_id)
+        {
+            self.release_nft_from_escrow(token_id, nonce, amount)?;
+
+            return Ok(Some(nonce));
+        }
+
+        Ok(None)
+    }
+
+    /// Adds quantity to an already mapped nonce, or creates a new one
+    fn mint_unlocked_nft(
+        &self,
+        token_id: &TokenIdentifier,
+        args: &NftTransactionArgs<Self::BigUint>,
+    ) -> SCResult<u64> {
+        let nonce_mapper = self.nft_nonce(token_id, &args.token_id);
+        if !nonce_mapper.is_empty() {
+            let nonce = nonce_mapper.get();
+            self.require_local_nft_add_quantity_role_set(token_id)?;
+            self.send().esdt_local_mint(token_id, nonce, &args.amount);
+
+            return Ok(nonce);
+        }
+
+        self.require_local_nft_create_role_set(token_id)?;
+
+        let nonce = self.send().esdt_nft_create::<BoxedBytes>(
+            token_id,
+            &args.amount,
+            &args.asset_hash,
+            &Self::BigUint::zero(),
+            &BoxedBytes::empty(),
+            &args.attributes,
+            &args.uris,
+        );
+        self.set_nft_token_id_mapping(token_id, nonce, &args.token_id);
+
+        Ok(nonce)
+    }
+
+    fn unlock_or_record_failure(
+        &self,
+        method_args: BoxedBytes,
+        from_chain_id: u64,
+        is_nft: bool,
+    ) -> MultiResult2<TransactionStatus, u64> {
+        let result = if is_nft {
+            self.try_unlock_nft(&method_args, from_chain_id)
+        } else {
+            self.try_unlock(&method_args, from_chain_id)
+        };
+
+        match result {
+            Ok(()) => (TransactionStatus::Executed, 0).into(),
+            Err(err) => {
+                let failed_unlock_id = self.failed_unlock_last_id().update(|last_id| {
+                    *last_id += 1;
+                    *last_id
+                });
+                let reason = BoxedBytes::from(err.as_bytes());
+
+                self.failed_unlock_ids().insert(failed_unlock_id);
+                self.failed_unlock(failed_unlock_id).set(&FailedUnlock {
+                    from_chain_id,
+                    method_args,
+                    reason,
+                    is_nft,
+                });
+                self.unlock_failed_event(failed_unlock_id, from_chain_id, err.as_bytes());
+
+                (TransactionStatus::Failed, failed_unlock_id).into()
+            }
+        }
+    }
+
+    fn try_take_failed_unlock(&self, failed_unlock_id: u64) -> SCResult<FailedUnlock> {
+        require!(
+            self.failed_unlock_ids().remove(&failed_unlock_id),
+            "Failed unlock not found"
+        );
+
+        let failed_unlock = self.failed_unlock(failed_unlock_id).get();
+        self.failed_unlock(failed_unlock_id).clear();
+
+        Ok(failed_unlock)
+    }
+
+    fn refund_unlock(&self, failed_unlock: &FailedUnlock) -> SCResult<()> {
+        let from_chain_id = failed_unlock.from_chain_id;
+        let args =
+            TransactionArgs::<Self::BigUint>::from_raw_bytes(failed_unlock.method_args.as_slice())?;
+        require!(
+            !args.from_address.is_empty(),
+            "Sender on the source chain is unknown"
+        );
+
+        let token_id = TokenIdentifier::from(args.asset_hash.as_slice());
+        let source_asset_hash = self.get_asset_hash(&token_id, from_chain_id);
+        require!(
+            !source_asset_hash.is_empty(),
+            "No asset mapping to refund the transfer"
+        );
+
+        // the amount is sent back as received, so it's already in the source chain's decimals
+        let refund_args = TransactionArgs {
+            asset_hash: source_asset_hash,
+            dest_address: args.from_address,
+            amount: args.amount,
+            from_address: BoxedBytes::empty(),
+            data: BoxedBytes::empty(),
+        };
+        self.create_unlock_tx(from_chain_id, &refund_args)
+    }
+
+    /// The tokens were neither released nor created, so the source chain only has to release them back
+    fn refund_nft_unlock(&self, failed_unlock: &FailedUnlock) -> SCResult<()> {
+        let from_chain_id = failed_unlock.from_chain_id;
+        let args = NftTransactionArgs::<Self::BigUint>::from_raw_bytes(
+            failed_unlock.method_args.as_slice(),
+        )?;
+        require!(
+            !args.from_address.is_empty(),
+            "Sender on the source chain is unknown"
+        );
+
+        let token_id = TokenIdentifier::from(args.asset_hash.as_slice());
+        let source_asset_hash = self.get_asset_hash(&token_id, from_chain_id);
+        require!(
+            !source_asset_hash.is_empty(),
+            "No asset mapping to refund the transfer"
+        );
+
+        let refund_args = NftTransactionArgs {
+            asset_hash: source_asset_hash,
+            dest_address: args.from_address,
+            token_id: args.token_id,
+            amount: args.amount,
+            attributes: args.attributes,
+            uris: args.uris,
+            from_address: BoxedBytes::empty(),
+        };
+        self.create_unlock_nft_tx(from_chain_id, &refund_args)
+    }
+
+    /// NFTs/SFTs are always held by the relayer on lock, so they can be released on unlock.
+    /// Nonces of custody collections keep their value as ID on other chains.
+    /// Any other nonce must have been created by the relayer, and so be already mapped.
+    fn lock_nft(
+        &self,
+        payment_token: TokenIdentifier,
+        nonce: u64,
+        payment_amount: Self::BigUint,
+        to_chain_id: u64,
+        dest_address: BoxedBytes,
+    ) -> SCResult<()> {
+        require!(payment_amount > 0, "Must bridge more than 0 tokens");
+
+        let dest_chain_proxy = self.get_chain_proxy(to_chain_id);
+        require!(
+            !dest_chain_proxy.is_empty(),
+            "Selected Chain ID not supported"
+        );
+
+        let to_asset_hash = self.get_asset_hash(&payment_token, to_chain_id);
+        require!(
+            !to_asset_hash.is_empty(),
+            "This specific token cannot be bridged"
+        );
+
+        let foreign_token_id = self.escrow_locked_nft(&payment_token, nonce, &payment_amount)?;
+
+        let token_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            &payment_token,
+            nonce,
+        );
+        let tx_args = NftTransactionArgs {
+            dest_address,
+            asset_hash: to_asset_hash,
+            token_id: foreign_token_id,
+            amount: payment_amount,
+            attributes: BoxedBytes::from(token_data.attributes.as_slice()),
+            uris: token_data.uris,
+            from_address: BoxedBytes::empty(),
+        };
+
+        self.create_unlock_nft_tx(to_chain_id, &tx_args)?;
+
+        let caller = self.blockchain().get_caller();
+        self.lock_nft_event(
+            &payment_token,
+            &caller,
+            to_chain_id,
+            &tx_args.asset_hash,
+            &tx_args.dest_address,
+            nonce,
+            &tx_args.token_id,
+            &tx_args.amount,
+        );
+
+        Ok(())
+    }
+
+    /// Escrows the locked NFTs, and returns their token ID on the other chains
+    fn escrow_locked_nft(
+        &self,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        amount: &Self::BigUint,
+    ) -> SCResult<Self::BigUint> {
+        let foreign_id_mapper = self.nft_foreign_token_id(token_id, nonce);
+        let foreign_token_id = if !foreign_id_mapper.is_empty() {
+            foreign_id_mapper.get()
+        } else {
+            require!(
+                self.is_custody_token(token_id),
+                "Token was not created by the bridge"
+            );
+
+            let foreign_token_id = Self::BigUint::from(nonce);
+            self.set_nft_token_id_mapping(token_id, nonce, &foreign_token_id);
+
+            foreign_token_id
+        };
+
+        self.escrowed_nft_balance(token_id, nonce)
+            .update(|balance| *balance += amount);
 
-    // endpoints - owner-only
+        Ok(foreign_token_id)
+    }
 
-    #[only_owner]
-    #[endpoint(setAssetHash)]
-    fn set_asset_hash(
+    /// If the receiver's call fails, the tokens are kept by the relayer, and the receiver may claim them later.
+    /// The call is synchronous, as receivers are in the relayer's shard, so a failure is always detected here.
+    fn deliver_to_receiver(
         &self,
-        token_id: TokenIdentifier,
-        to_chain_id: u64,
-        other_chain_asset_hash: BoxedBytes,
-    ) -> SCResult<()> {
-        self.asset_hash_map(&token_id, to_chain_id)
-            .set(&other_chain_asset_hash);
+        receiver_address: &Address,
+        token_id: &TokenIdentifier,
+        amount: &Self::BigUint,
+        from_chain_id: u64,
+        from_address: &BoxedBytes,
+        data: &BoxedBytes,
+    ) {
+        let mut serialized_from_chain_id = Vec::new();
+        let _ = from_chain_id.top_encode(&mut serialized_from_chain_id);
 
-        Ok(())
+        let mut arg_buffer = ArgBuffer::new();
+        arg_buffer.push_argument_bytes(serialized_from_chain_id.as_slice());
+        arg_buffer.push_argument_bytes(from_address.as_slice());
+        arg_buffer.push_argument_bytes(data.as_slice());
+
+        let gas_limit = self
+            .blockchain()
+            .get_gas_left()
+            .saturating_sub(BRIDGE_RECEIVE_GAS_RESERVE);
+        let result = self.send().direct_esdt_execute(
+            receiver_address,
+            token_id,
+            amount,
+            gas_limit,
+            BRIDGE_RECEIVE_ENDPOINT_NAME,
+            &arg_buffer,
+        );
+
+        if result.is_err() {
+            self.keep_for_receiver(receiver_address, token_id, amount, from_chain_id);
+        }
     }
 
-    #[only_owner]
-    #[endpoint(setChainProxy)]
-    fn set_chain_proxy_hash(&self, chain_id: u64, proxy_hash: BoxedBytes) -> SCResult<()> {
-        self.proxy_hash_map(chain_id).set(&proxy_hash);
+    fn keep_for_receiver(
+        &self,
+        receiver_address: &Address,
+        token_id: &TokenIdentifier,
+        amount: &Self::BigUint,
+        from_chain_id: u64,
+    ) {
+        self.claimable_balance(receiver_address, token_id)
+            .update(|balance| *balance += amount);
+        self.bridge_receive_failed_event(token_id, receiver_address, from_chain_id, amount);
+    }
 
-        Ok(())
+    fn is_in_relayer_shard(&self, address: &Address) -> bool {
+        let own_address = self.blockchain().get_sc_address();
+
+        self.blockchain().get_shard_of_address(address)
+            == self.blockchain().get_shard_of_address(&own_address)
     }
 
-    #[only_owner]
-    #[endpoint]
-    fn unlock(
+    fn set_nft_token_id_mapping(
         &self,
-        args: TransactionArgs<Self::BigUint>,
-        from_contract_address: BoxedBytes,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        foreign_token_id: &Self::BigUint,
+    ) {
+        self.nft_nonce(token_id, foreign_token_id).set(&nonce);
+        self.nft_foreign_token_id(token_id, nonce)
+            .set(foreign_token_id);
+    }
+
+    fn require_from_proxy_contract(
+        &self,
+        from_contract_address: &BoxedBytes,
         from_chain_id: u64,
     ) -> SCResult<()> {
         require!(
@@ -67,80 +1292,76 @@ pub trait TransactionRelayer {
             "from_contract_address cannot be empty"
         );
 
-        let from_proxy_contract = self.proxy_hash_map(from_chain_id).get();
+        let from_proxy_contract = self.get_chain_proxy(from_chain_id);
         require!(
-            from_contract_address == from_proxy_contract,
+            from_contract_address == &from_proxy_contract,
             "from_contract_address is not the expected proxy contract address"
         );
 
-        require!(!args.asset_hash.is_empty(), "asset_hash cannot be empty");
+        Ok(())
+    }
 
-        let elrond_dest_address = self.try_convert_to_elrond_address(&args.dest_address)?;
-        require!(
-            !self.blockchain().is_smart_contract(&elrond_dest_address),
-            "cannot transfer to smart contract"
-        );
+    fn try_get_unlock_destination(
+        &self,
+        asset_hash: &BoxedBytes,
+        dest_address: &BoxedBytes,
+    ) -> SCResult<(TokenIdentifier, Address)> {
+        require!(!asset_hash.is_empty(), "asset_hash cannot be empty");
 
-        let token_id = TokenIdentifier::from(args.asset_hash.as_slice());
+        let elrond_dest_address = self.try_convert_to_elrond_address(dest_address)?;
+
+        let token_id = TokenIdentifier::from(asset_hash.as_slice());
         require!(
             token_id.is_valid_esdt_identifier(),
             "Invalid Token ID provided"
         );
 
-        self.try_mint(&token_id, &args.amount)?;
-        self.send().direct(
-            &elrond_dest_address,
-            &token_id,
-            0,
-            &args.amount,
-            b"PolyBridge Transfer",
+        Ok((token_id, elrond_dest_address))
+    }
+
+    fn create_unlock_tx(
+        &self,
+        to_chain_id: u64,
+        tx_args: &TransactionArgs<Self::BigUint>,
+    ) -> SCResult<()> {
+        let dest_chain_proxy = self.get_chain_proxy(to_chain_id);
+        require!(
+            !dest_chain_proxy.is_empty(),
+            "Selected Chain ID not supported"
         );
 
-        self.unlock_event(&token_id, &elrond_dest_address, &args.amount);
+        let raw_tx_args = match tx_args.to_raw_bytes() {
+            Result::Ok(raw_args) => raw_args,
+            Result::Err(_) => return sc_error!("Failed to serialize transaction args"),
+        };
+
+        let ccm_address = self.get_cross_chain_management_sc_address();
+        self.cross_chain_management_proxy(ccm_address)
+            .create_cross_chain_tx(
+                to_chain_id,
+                dest_chain_proxy,
+                UNLOCK_METHOD_NAME.into(),
+                raw_tx_args,
+            )
+            .execute_on_dest_context();
 
         Ok(())
     }
 
-    // endpoints
-
-    #[payable("*")]
-    #[endpoint]
-    fn lock(
+    fn create_unlock_nft_tx(
         &self,
-        #[payment_token] payment_token: TokenIdentifier,
-        #[payment_amount] payment_amount: Self::BigUint,
         to_chain_id: u64,
-        dest_address: BoxedBytes,
+        tx_args: &NftTransactionArgs<Self::BigUint>,
     ) -> SCResult<()> {
-        require!(
-            payment_token.is_esdt(),
-            "eGLD payment not allowed, wrap your EGLD first"
-        );
-        require!(
-            self.call_value().esdt_token_nonce() == 0,
-            "Can only bridge fungible ESDTs"
-        );
-        require!(payment_amount > 0, "Must bridge more than 0 tokens");
-
-        let dest_chain_proxy = self.proxy_hash_map(to_chain_id).get();
+        let dest_chain_proxy = self.get_chain_proxy(to_chain_id);
         require!(
             !dest_chain_proxy.is_empty(),
             "Selected Chain ID not supported"
         );
 
-        let to_asset_hash = self.asset_hash_map(&payment_token, to_chain_id).get();
-        require!(
-            !to_asset_hash.is_empty(),
-            "This specific token cannot be bridged"
-        );
-
-        self.try_burn(&payment_token, &payment_amount)?;
-
-        let caller = self.blockchain().get_caller();
-        let tx_args = TransactionArgs {
-            dest_address,
-            asset_hash: to_asset_hash,
-            amount: payment_amount,
+        let raw_tx_args = match tx_args.to_raw_bytes() {
+            Result::Ok(raw_args) => raw_args,
+            Result::Err(_) => return sc_error!("Failed to serialize transaction args"),
         };
 
         let ccm_address = self.get_cross_chain_management_sc_address();
@@ -148,25 +1369,41 @@ pub trait TransactionRelayer {
             .create_cross_chain_tx(
                 to_chain_id,
                 dest_chain_proxy,
-                UNLOCK_METHOD_NAME.into(),
-                tx_args.clone(),
+                UNLOCK_NFT_METHOD_NAME.into(),
+                raw_tx_args,
             )
             .execute_on_dest_context();
 
-        self.lock_event(
-            &payment_token,
-            &caller,
-            to_chain_id,
-            &tx_args.asset_hash,
-            &tx_args.dest_address,
-            &tx_args.amount,
+        Ok(())
+    }
+
+    /// Returns empty if the chain is not supported
+    fn get_chain_proxy(&self, chain_id: u64) -> BoxedBytes {
+        self.supported_chains()
+            .get(&chain_id)
+            .unwrap_or_else(BoxedBytes::empty)
+    }
+
+    /// Returns empty if the token is not registered for the chain
+    fn get_asset_hash(&self, token_id: &TokenIdentifier, chain_id: u64) -> BoxedBytes {
+        self.chain_assets(chain_id)
+            .get(token_id)
+            .unwrap_or_else(BoxedBytes::empty)
+    }
+
+    fn require_token_registered(
+        &self,
+        token_id: &TokenIdentifier,
+        from_chain_id: u64,
+    ) -> SCResult<()> {
+        require!(
+            self.chain_assets(from_chain_id).contains_key(token_id),
+            "Token not registered for the source chain"
         );
 
         Ok(())
     }
 
-    // private
-
     fn get_cross_chain_management_sc_address(&self) -> Address {
         self.blockchain().get_owner_address()
     }
@@ -180,6 +1417,141 @@ pub trait TransactionRelayer {
         Ok(Address::from_slice(address.as_slice()))
     }
 
+    fn is_wrapped_egld(&self, token_id: &TokenIdentifier) -> bool {
+        !self.wrapped_egld_token_id().is_empty() && token_id == &self.wrapped_egld_token_id().get()
+    }
+
+    /// Returns the amount in the remote chain's decimals, the dust to be refunded to the sender,
+    /// and the dust kept by the bridge
+    fn to_remote_amount(
+        &self,
+        token_id: &TokenIdentifier,
+        to_chain_id: u64,
+        amount: &Self::BigUint,
+    ) -> SCResult<(Self::BigUint, Self::BigUint, Self::BigUint)> {
+        let decimals_mapper = self.asset_decimals(token_id, to_chain_id);
+        if decimals_mapper.is_empty() {
+            return Ok((amount.clone(), Self::BigUint::zero(), Self::BigUint::zero()));
+        }
+
+        let asset_decimals = decimals_mapper.get();
+        let (remote_amount, dust) = decimals::scale_amount(
+            amount,
+            asset_decimals.local_decimals,
+            asset_decimals.remote_decimals,
+        );
+        if dust == 0 {
+            return Ok((remote_amount, Self::BigUint::zero(), dust));
+        }
+
+        match asset_decimals.dust_policy {
+            DustPolicy::Reject => sc_error!("Amount would lose precision on the destination chain"),
+            DustPolicy::Refund => Ok((remote_amount, dust, Self::BigUint::zero())),
+            DustPolicy::RoundDown => Ok((remote_amount, Self::BigUint::zero(), dust)),
+        }
+    }
+
+    /// Dust from inbound amounts cannot be refunded, so it's rounded down unless rejected
+    fn to_local_amount(
+        &self,
+        token_id: &TokenIdentifier,
+        from_chain_id: u64,
+        remote_amount: &Self::BigUint,
+    ) -> SCResult<Self::BigUint> {
+        let decimals_mapper = self.asset_decimals(token_id, from_chain_id);
+        if decimals_mapper.is_empty() {
+            return Ok(remote_amount.clone());
+        }
+
+        let asset_decimals = decimals_mapper.get();
+        let (local_amount, dust) = decimals::scale_amount(
+            remote_amount,
+            asset_decimals.remote_decimals,
+            asset_decimals.local_decimals,
+        );
+        require!(
+            dust == 0 || asset_decimals.dust_policy != DustPolicy::Reject,
+            "Amount would lose precision on Elrond"
+        );
+
+        Ok(local_amount)
+    }
+
+    fn refund_dust(&self, to: &Address, token_id: &TokenIdentifier, amount: &Self::BigUint) {
+        if self.is_wrapped_egld(token_id) {
+            let token_manager_address = self.egld_token_manager_address().get();
+            self.esdt_token_manager_proxy(token_manager_address)
+                .unwrap_egld(
+                    token_id.clone(),
+                    amount.clone(),
+                    OptionalArg::Some(to.clone()),
+                )
+                .execute_on_dest_context();
+        } else {
+            self.send()
+                .direct(to, token_id, 0, amount, b"PolyBridge dust refund");
+        }
+    }
+
+    fn is_custody_token(&self, token_id: &TokenIdentifier) -> bool {
+        self.is_wrapped_egld(token_id) || self.token_mode(token_id).get() == TokenMode::Custody
+    }
+
+    fn release_from_escrow(
+        &self,
+        token_id: &TokenIdentifier,
+        amount: &Self::BigUint,
+    ) -> SCResult<()> {
+        let escrowed_balance_mapper = self.escrowed_balance(token_id);
+        let escrowed_balance = escrowed_balance_mapper.get();
+        require!(&escrowed_balance >= amount, "Not enough tokens in escrow");
+
+        escrowed_balance_mapper.set(&(escrowed_balance - amount));
+
+        Ok(())
+    }
+
+    fn release_nft_from_escrow(
+        &self,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        amount: &Self::BigUint,
+    ) -> SCResult<()> {
+        let escrowed_balance_mapper = self.escrowed_nft_balance(token_id, nonce);
+        let escrowed_balance = escrowed_balance_mapper.get();
+        require!(&escrowed_balance >= amount, "Not enough tokens in escrow");
+
+        escrowed_balance_mapper.set(&(escrowed_balance - amount));
+
+        Ok(())
+    }
+
+    fn require_egld_token_manager_set(&self) -> SCResult<()> {
+        require!(
+            !self.egld_token_manager_address().is_empty(),
+            "EsdtTokenManager address not set"
+        );
+
+        Ok(())
+    }
+
+    /// The unwrap is a synchronous call, which would revert the whole unlock if it failed
+    fn require_egld_unwrappable(&self, amount: &Self::BigUint) -> SCResult<()> {
+        self.require_egld_token_manager_set()?;
+
+        let token_manager_address = self.egld_token_manager_address().get();
+        require!(
+            self.is_in_relayer_shard(&token_manager_address),
+            "EsdtTokenManager must be in the same shard as the relayer"
+        );
+        require!(
+            &self.blockchain().get_balance(&token_manager_address) >= amount,
+            "Not enough eGLD in the EsdtTokenManager"
+        );
+
+        Ok(())
+    }
+
     fn try_mint(&self, token_id: &TokenIdentifier, amount: &Self::BigUint) -> SCResult<()> {
         self.require_local_mint_role_set(token_id)?;
         self.send().esdt_local_mint(token_id, 0, amount);
@@ -216,22 +1588,147 @@ pub trait TransactionRelayer {
         Ok(())
     }
 
+    fn require_local_nft_create_role_set(&self, token_id: &TokenIdentifier) -> SCResult<()> {
+        let roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        require!(
+            roles.contains(&EsdtLocalRole::NftCreate),
+            "Local NFT create role not set"
+        );
+
+        Ok(())
+    }
+
+    fn require_local_nft_add_quantity_role_set(&self, token_id: &TokenIdentifier) -> SCResult<()> {
+        let roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        require!(
+            roles.contains(&EsdtLocalRole::NftAddQuantity),
+            "Local NFT add quantity role not set"
+        );
+
+        Ok(())
+    }
+
     // storage
 
-    /// Elrond token ID to another chain's representation of that token (for example, an ERC20 contract address)
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<Self::Storage, u32>;
+
+    /// Legacy registries, only read by the storage migration
     #[storage_mapper("assetHashMap")]
-    fn asset_hash_map(
+    fn legacy_asset_hash_map(
         &self,
         token_id: &TokenIdentifier,
         to_chain_id: u64,
     ) -> SingleValueMapper<Self::Storage, BoxedBytes>;
 
-    /// Chain ID to proxy contract, which is likely the CrossChainManagement SC on the other chain
     #[storage_mapper("proxyHashMap")]
-    fn proxy_hash_map(&self, to_chain_id: u64) -> SingleValueMapper<Self::Storage, BoxedBytes>;
+    fn legacy_proxy_hash_map(
+        &self,
+        to_chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, BoxedBytes>;
+
+    /// Chain ID to proxy contract, which is likely the CrossChainManagement SC on the other chain
+    #[storage_mapper("supportedChains")]
+    fn supported_chains(&self) -> MapMapper<Self::Storage, u64, BoxedBytes>;
+
+    /// Elrond token ID to the chain's representation of that token (for example, an ERC20 contract address)
+    #[storage_mapper("chainAssets")]
+    fn chain_assets(&self, chain_id: u64) -> MapMapper<Self::Storage, TokenIdentifier, BoxedBytes>;
+
+    /// The reverse of chain_assets
+    #[storage_mapper("chainAssetTokens")]
+    fn chain_asset_tokens(
+        &self,
+        chain_id: u64,
+    ) -> MapMapper<Self::Storage, BoxedBytes, TokenIdentifier>;
+
+    #[storage_mapper("assetDecimals")]
+    fn asset_decimals(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, AssetDecimals>;
+
+    #[storage_mapper("tokenMode")]
+    fn token_mode(&self, token_id: &TokenIdentifier)
+        -> SingleValueMapper<Self::Storage, TokenMode>;
+
+    /// Tokens locked in custody and not yet released, by token
+    #[storage_mapper("escrowedBalance")]
+    fn escrowed_balance(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    /// Dust kept from custody locks under the RoundDown policy. Not part of the escrowed balance.
+    #[storage_mapper("roundedDownDust")]
+    fn rounded_down_dust(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[storage_mapper("failedUnlockLastId")]
+    fn failed_unlock_last_id(&self) -> SingleValueMapper<Self::Storage, u64>;
+
+    /// IDs of the failed unlocks which were neither retried successfully nor refunded
+    #[storage_mapper("failedUnlockIds")]
+    fn failed_unlock_ids(&self) -> SetMapper<Self::Storage, u64>;
+
+    #[storage_mapper("failedUnlock")]
+    fn failed_unlock(
+        &self,
+        failed_unlock_id: u64,
+    ) -> SingleValueMapper<Self::Storage, FailedUnlock>;
+
+    /// Contracts allowed as destination for unlocked tokens
+    #[storage_mapper("bridgeReceivers")]
+    fn bridge_receivers(&self) -> SetMapper<Self::Storage, Address>;
+
+    /// Tokens kept after a failed delivery to a bridge receiver contract
+    #[storage_mapper("claimableBalance")]
+    fn claimable_balance(
+        &self,
+        receiver_address: &Address,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    /// NFTs/SFTs held by the relayer, by token and nonce
+    #[storage_mapper("escrowedNftBalance")]
+    fn escrowed_nft_balance(
+        &self,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    /// The other chains' token ID (for example, an ERC721 tokenId) to the Elrond nonce, by collection
+    #[storage_mapper("nftNonce")]
+    fn nft_nonce(
+        &self,
+        token_id: &TokenIdentifier,
+        foreign_token_id: &Self::BigUint,
+    ) -> SingleValueMapper<Self::Storage, u64>;
+
+    #[storage_mapper("nftForeignTokenId")]
+    fn nft_foreign_token_id(
+        &self,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[view(getEgldTokenManagerAddress)]
+    #[storage_mapper("egldTokenManagerAddress")]
+    fn egld_token_manager_address(&self) -> SingleValueMapper<Self::Storage, Address>;
+
+    #[view(getWrappedEgldTokenId)]
+    #[storage_mapper("wrappedEgldTokenId")]
+    fn wrapped_egld_token_id(&self) -> SingleValueMapper<Self::Storage, TokenIdentifier>;
 
     // events
 
+    /// The amount is the net amount bridged, i.e. without the fee and the refunded dust
     #[event("lock_event")]
     fn lock_event(
         &self,
@@ -251,6 +1748,51 @@ pub trait TransactionRelayer {
         amount: &Self::BigUint,
     );
 
+    #[event("unlock_failed_event")]
+    fn unlock_failed_event(
+        &self,
+        #[indexed] failed_unlock_id: u64,
+        #[indexed] from_chain_id: u64,
+        reason: &[u8],
+    );
+
+    #[event("failed_unlock_resolved_event")]
+    fn failed_unlock_resolved_event(&self, #[indexed] failed_unlock_id: u64, refunded: bool);
+
+    #[event("bridge_receive_failed_event")]
+    fn bridge_receive_failed_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] receiver: &Address,
+        #[indexed] from_chain_id: u64,
+        amount: &Self::BigUint,
+    );
+
+    #[event("lock_nft_event")]
+    fn lock_nft_event(
+        &self,
+        #[indexed] from_asset: &TokenIdentifier,
+        #[indexed] sender: &Address,
+        #[indexed] to_chain_id: u64,
+        #[indexed] to_asset_hash: &BoxedBytes,
+        #[indexed] dest_address: &BoxedBytes,
+        #[indexed] nonce: u64,
+        #[indexed] to_token_id: &Self::BigUint,
+        amount: &Self::BigUint,
+    );
+
+    #[event("unlock_nft_event")]
+    fn unlock_nft_event(
+        &self,
+        #[indexed] to_asset: &TokenIdentifier,
+        #[indexed] receiver: &Address,
+        #[indexed] nonce: u64,
+        amount: &Self::BigUint,
+    );
+
+    #[event("storage_migrated_event")]
+    fn storage_migrated_event(&self, #[indexed] from_version: u32, to_version: u32);
+
     // proxies
 
     #[proxy]
@@ -258,4 +1800,7 @@ pub trait TransactionRelayer {
         &self,
         to: Address,
     ) -> cross_chain_management_proxy::Proxy<Self::SendApi>;
+
+    #[proxy]
+    fn esdt_token_manager_proxy(&self, to: Address) -> esdt_token_manager::Proxy<Self::SendApi>;
 }
@@ -0,0 +1,261 @@
+use access_control::Role;
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Length of a volume bucket, in seconds
+pub const VOLUME_BUCKET_DURATION: u64 = 60 * 60;
+
+/// Number of buckets in the rolling window, i.e. 24 hours
+pub const VOLUME_BUCKET_COUNT: u64 = 24;
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum TransferDirection {
+    /// other chain -> Elrond
+    Inbound,
+    /// Elrond -> other chain
+    Outbound,
+}
+
+/// Volume transferred during one hour. Buckets are reused every 24 hours,
+/// so the hour tells whether the amount is still within the rolling window.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct VolumeBucket<BigUint: BigUintApi> {
+    pub hour: u64,
+    pub amount: BigUint,
+}
+
+/// Per token and chain transfer limits, all in the Elrond token's decimals.
+/// Unset limits are not enforced. NFT/SFT transfers are not limited.
+#[elrond_wasm::module]
+pub trait LimitsModule: access_control::AccessControlModule {
+    // endpoints - asset managers
+
+    /// Use 0 for no limit. The minimum only applies to outbound transfers,
+    /// as inbound ones were already accepted on the source chain.
+    #[endpoint(setTransferLimits)]
+    fn set_transfer_limits(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        min_amount: Self::BigUint,
+        max_amount: Self::BigUint,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+        require!(
+            max_amount == 0 || min_amount <= max_amount,
+            "Minimum amount cannot be greater than the maximum amount"
+        );
+
+        self.min_transfer_amount(&token_id, chain_id)
+            .set(&min_amount);
+        self.max_transfer_amount(&token_id, chain_id)
+            .set(&max_amount);
+
+        Ok(())
+    }
+
+    /// Maximum volume over the last 24 hours. Use 0 for no cap.
+    #[endpoint(setDailyCap)]
+    fn set_daily_cap(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+        cap: Self::BigUint,
+    ) -> SCResult<()> {
+        self.require_role(Role::AssetManager)?;
+
+        self.daily_cap(&token_id, chain_id, direction).set(&cap);
+
+        Ok(())
+    }
+
+    // views
+
+    #[view(getTransferLimits)]
+    fn get_transfer_limits(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+    ) -> MultiResult2<Self::BigUint, Self::BigUint> {
+        (
+            self.min_transfer_amount(&token_id, chain_id).get(),
+            self.max_transfer_amount(&token_id, chain_id).get(),
+        )
+            .into()
+    }
+
+    #[view(getDailyCap)]
+    fn get_daily_cap(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+    ) -> Self::BigUint {
+        self.daily_cap(&token_id, chain_id, direction).get()
+    }
+
+    #[view(getRollingVolume)]
+    fn get_rolling_volume(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+    ) -> Self::BigUint {
+        self.rolling_volume(&token_id, chain_id, direction)
+    }
+
+    /// Amount which can still be transferred before reaching the daily cap
+    #[view(getRemainingDailyCapacity)]
+    fn get_remaining_daily_capacity(
+        &self,
+        token_id: TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+    ) -> SCResult<Self::BigUint> {
+        let cap = self.daily_cap(&token_id, chain_id, direction).get();
+        require!(cap > 0, "No daily cap set");
+
+        let volume = self.rolling_volume(&token_id, chain_id, direction);
+        if volume >= cap {
+            return Ok(Self::BigUint::zero());
+        }
+
+        Ok(cap - volume)
+    }
+
+    // private
+
+    fn check_transfer_limits(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+        amount: &Self::BigUint,
+    ) -> SCResult<()> {
+        if direction == TransferDirection::Outbound {
+            require!(
+                amount >= &self.min_transfer_amount(token_id, chain_id).get(),
+                "Amount below the minimum transfer amount"
+            );
+        }
+
+        let max_amount = self.max_transfer_amount(token_id, chain_id).get();
+        require!(
+            max_amount == 0 || amount <= &max_amount,
+            "Amount above the maximum transfer amount"
+        );
+
+        let cap = self.daily_cap(token_id, chain_id, direction).get();
+        if cap == 0 {
+            return Ok(());
+        }
+
+        let new_volume = self.rolling_volume(token_id, chain_id, direction) + amount.clone();
+        if new_volume > cap {
+            return match direction {
+                TransferDirection::Inbound => sc_error!("Daily inbound cap exceeded"),
+                TransferDirection::Outbound => sc_error!("Daily outbound cap exceeded"),
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Adds the transfer to the rolling volume. Only needed if the transfer is capped.
+    fn record_transfer_volume(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+        amount: &Self::BigUint,
+    ) {
+        if self.daily_cap(token_id, chain_id, direction).is_empty() {
+            return;
+        }
+
+        let current_hour = self.get_current_hour();
+        let bucket_mapper = self.volume_bucket(
+            token_id,
+            chain_id,
+            direction,
+            current_hour % VOLUME_BUCKET_COUNT,
+        );
+        let mut bucket = if !bucket_mapper.is_empty() {
+            bucket_mapper.get()
+        } else {
+            VolumeBucket {
+                hour: current_hour,
+                amount: Self::BigUint::zero(),
+            }
+        };
+        if bucket.hour != current_hour {
+            bucket.hour = current_hour;
+            bucket.amount = Self::BigUint::zero();
+        }
+        bucket.amount += amount;
+        bucket_mapper.set(&bucket);
+    }
+
+    fn rolling_volume(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+    ) -> Self::BigUint {
+        let current_hour = self.get_current_hour();
+        let mut volume = Self::BigUint::zero();
+
+        for slot in 0..VOLUME_BUCKET_COUNT {
+            let bucket_mapper = self.volume_bucket(token_id, chain_id, direction, slot);
+            if bucket_mapper.is_empty() {
+                continue;
+            }
+
+            let bucket = bucket_mapper.get();
+            if bucket.hour + VOLUME_BUCKET_COUNT > current_hour {
+                volume += &bucket.amount;
+            }
+        }
+
+        volume
+    }
+
+    fn get_current_hour(&self) -> u64 {
+        self.blockchain().get_block_timestamp() / VOLUME_BUCKET_DURATION
+    }
+
+    // storage
+
+    #[storage_mapper("minTransferAmount")]
+    fn min_transfer_amount(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[storage_mapper("maxTransferAmount")]
+    fn max_transfer_amount(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[storage_mapper("dailyCap")]
+    fn daily_cap(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+    ) -> SingleValueMapper<Self::Storage, Self::BigUint>;
+
+    #[storage_mapper("volumeBucket")]
+    fn volume_bucket(
+        &self,
+        token_id: &TokenIdentifier,
+        chain_id: u64,
+        direction: TransferDirection,
+        slot: u64,
+    ) -> SingleValueMapper<Self::Storage, VolumeBucket<Self::BigUint>>;
+}
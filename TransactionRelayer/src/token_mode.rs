@@ -0,0 +1,10 @@
+elrond_wasm::derive_imports!();
+
+/// How the relayer handles a token when bridging it
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum TokenMode {
+    /// Bridge-issued tokens, burned on lock and minted on unlock. Requires the local mint/burn roles.
+    MintBurn,
+    /// Elrond-native tokens, held in escrow on lock and released on unlock
+    Custody,
+}
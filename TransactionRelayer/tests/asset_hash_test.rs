@@ -0,0 +1,113 @@
+use access_control::{AccessControlModule, Role};
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, TokenIdentifier};
+use elrond_wasm_debug::TxContext;
+use transaction_relayer::*;
+
+const CHAIN_ID: u64 = 5;
+const OTHER_CHAIN_ID: u64 = 6;
+const ASSET_HASH: &[u8] = b"usdc_on_another_chain";
+const OTHER_ASSET_HASH: &[u8] = b"other_usdc_on_another_chain";
+
+fn usdc() -> TokenIdentifier {
+    TokenIdentifier::from(&b"USDC-123456"[..])
+}
+
+fn weth() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WETH-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn ok<T>(result: SCResult<T>) -> T {
+    match result {
+        SCResult::Ok(value) => value,
+        SCResult::Err(err) => panic!("Call failed: {:?}", err.as_bytes()),
+    }
+}
+
+/// The dummy context's caller is the zero address, which is made an asset manager
+fn setup() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .role_members(Role::AssetManager)
+        .insert(Address::zero());
+    relayer.supported_chains().insert(
+        CHAIN_ID,
+        BoxedBytes::from(&b"tx_relayer_addr_other_chain"[..]),
+    );
+    relayer.supported_chains().insert(
+        OTHER_CHAIN_ID,
+        BoxedBytes::from(&b"tx_relayer_addr_third_chain"[..]),
+    );
+
+    relayer
+}
+
+#[test]
+fn asset_hash_of_another_token_test() {
+    let relayer = setup();
+    assert!(relayer
+        .set_asset_hash(usdc(), CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+
+    assert_err(
+        relayer.set_asset_hash(weth(), CHAIN_ID, BoxedBytes::from(ASSET_HASH)),
+        b"Asset hash already registered for another token",
+    );
+    assert_eq!(
+        ok(relayer.get_token_for_asset_hash(CHAIN_ID, BoxedBytes::from(ASSET_HASH))),
+        usdc()
+    );
+    assert!(relayer.chain_assets(CHAIN_ID).get(&weth()).is_none());
+}
+
+#[test]
+fn same_asset_hash_on_another_chain_test() {
+    let relayer = setup();
+    assert!(relayer
+        .set_asset_hash(usdc(), CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+
+    // asset hashes are only unique per chain
+    assert!(relayer
+        .set_asset_hash(weth(), OTHER_CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+    assert_eq!(
+        ok(relayer.get_token_for_asset_hash(OTHER_CHAIN_ID, BoxedBytes::from(ASSET_HASH))),
+        weth()
+    );
+}
+
+#[test]
+fn change_asset_hash_test() {
+    let relayer = setup();
+    assert!(relayer
+        .set_asset_hash(usdc(), CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+    // setting the same mapping again is allowed
+    assert!(relayer
+        .set_asset_hash(usdc(), CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+
+    assert!(relayer
+        .set_asset_hash(usdc(), CHAIN_ID, BoxedBytes::from(OTHER_ASSET_HASH))
+        .is_ok());
+    assert_eq!(
+        ok(relayer.get_token_asset_hash(usdc(), CHAIN_ID)),
+        BoxedBytes::from(OTHER_ASSET_HASH)
+    );
+
+    // the previous asset hash is freed for other tokens
+    assert!(relayer
+        .set_asset_hash(weth(), CHAIN_ID, BoxedBytes::from(ASSET_HASH))
+        .is_ok());
+    assert_eq!(
+        ok(relayer.get_token_for_asset_hash(CHAIN_ID, BoxedBytes::from(ASSET_HASH))),
+        weth()
+    );
+}
@@ -0,0 +1,69 @@
+use access_control::{AccessControlModule, Role};
+use elrond_wasm::types::{Address, SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::*;
+
+const SOURCE_CHAIN_ID: u64 = 5;
+const RECEIVER_ADDRESS: [u8; 32] = [7u8; 32];
+
+fn token_id() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WEGLD-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+/// The receiver's onBridgeReceive call cannot run here, so its failure is handled directly
+#[test]
+fn failed_bridge_receive_is_claimable_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    let receiver_address = Address::from(RECEIVER_ADDRESS);
+
+    relayer.keep_for_receiver(
+        &receiver_address,
+        &token_id(),
+        &RustBigUint::from(1_000u64),
+        SOURCE_CHAIN_ID,
+    );
+    relayer.keep_for_receiver(
+        &receiver_address,
+        &token_id(),
+        &RustBigUint::from(500u64),
+        SOURCE_CHAIN_ID,
+    );
+
+    assert_eq!(
+        relayer.get_claimable_balance(receiver_address, token_id()),
+        RustBigUint::from(1_500u64)
+    );
+    // only the receiver itself may claim them
+    assert_eq!(
+        relayer.get_claimable_balance(Address::zero(), token_id()),
+        RustBigUint::from(0u64)
+    );
+}
+
+#[test]
+fn claim_without_balance_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+
+    assert_err(
+        relayer.claim_bridged_tokens(token_id()),
+        b"Nothing to claim",
+    );
+}
+
+#[test]
+fn unregister_unknown_receiver_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer.role_members(Role::Admin).insert(Address::zero());
+
+    assert_err(
+        relayer.unregister_bridge_receiver(Address::from(RECEIVER_ADDRESS)),
+        b"Receiver not registered",
+    );
+}
@@ -0,0 +1,170 @@
+use access_control::{AccessControlModule, Role};
+use elrond_wasm::types::{Address, OptionalArg, SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::fees::FeesModule;
+use transaction_relayer::*;
+
+const CHAIN_ID: u64 = 5;
+
+fn token_id() -> TokenIdentifier {
+    TokenIdentifier::from(&b"USDC-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+/// The dummy context's caller is the zero address, which is made an asset manager and fee collector
+fn setup() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .role_members(Role::AssetManager)
+        .insert(Address::zero());
+    relayer
+        .role_members(Role::FeeCollector)
+        .insert(Address::zero());
+    relayer.token_mode(&token_id()).set(&TokenMode::Custody);
+
+    relayer
+}
+
+fn set_decimals(relayer: &impl TransactionRelayer, dust_policy: DustPolicy) {
+    relayer
+        .asset_decimals(&token_id(), CHAIN_ID)
+        .set(&AssetDecimals {
+            local_decimals: 18,
+            remote_decimals: 6,
+            dust_policy,
+        });
+}
+
+fn remote_amount(
+    relayer: &impl TransactionRelayer,
+    amount: u64,
+) -> (RustBigUint, RustBigUint, RustBigUint) {
+    match relayer.to_remote_amount(&token_id(), CHAIN_ID, &RustBigUint::from(amount)) {
+        SCResult::Ok(amounts) => amounts,
+        SCResult::Err(_) => panic!("Amount should have been converted"),
+    }
+}
+
+#[test]
+fn release_from_escrow_test() {
+    let relayer = setup();
+    relayer
+        .escrowed_balance(&token_id())
+        .set(&RustBigUint::from(1_000u64));
+
+    assert_err(
+        relayer.release_from_escrow(&token_id(), &RustBigUint::from(1_001u64)),
+        b"Not enough tokens in escrow",
+    );
+    assert!(relayer
+        .release_from_escrow(&token_id(), &RustBigUint::from(1_000u64))
+        .is_ok());
+    assert_eq!(
+        relayer.escrowed_balance(&token_id()).get(),
+        RustBigUint::from(0u64)
+    );
+}
+
+#[test]
+fn leave_custody_with_escrowed_tokens_test() {
+    let relayer = setup();
+    relayer
+        .escrowed_balance(&token_id())
+        .set(&RustBigUint::from(1_000u64));
+
+    assert_err(
+        relayer.set_token_mode(token_id(), TokenMode::MintBurn),
+        b"Cannot leave custody mode while tokens are escrowed",
+    );
+
+    assert!(relayer
+        .release_from_escrow(&token_id(), &RustBigUint::from(1_000u64))
+        .is_ok());
+    assert!(relayer
+        .set_token_mode(token_id(), TokenMode::MintBurn)
+        .is_ok());
+    assert!(!relayer.is_custody_token(&token_id()));
+}
+
+#[test]
+fn leave_custody_with_rounded_down_dust_test() {
+    let relayer = setup();
+    relayer
+        .rounded_down_dust(&token_id())
+        .set(&RustBigUint::from(5u64));
+
+    // the dust is not escrowed, so it does not keep the token in custody
+    assert!(relayer
+        .set_token_mode(token_id(), TokenMode::MintBurn)
+        .is_ok());
+}
+
+#[test]
+fn round_down_dust_is_kept_apart_test() {
+    let relayer = setup();
+    set_decimals(&relayer, DustPolicy::RoundDown);
+
+    let (remote, refund, kept_dust) = remote_amount(&relayer, 1_000_000_000_005);
+    assert_eq!(remote, RustBigUint::from(1u64));
+    assert_eq!(refund, RustBigUint::from(0u64));
+    assert_eq!(kept_dust, RustBigUint::from(5u64));
+}
+
+#[test]
+fn refunded_dust_is_not_kept_test() {
+    let relayer = setup();
+    set_decimals(&relayer, DustPolicy::Refund);
+
+    let (remote, refund, kept_dust) = remote_amount(&relayer, 1_000_000_000_005);
+    assert_eq!(remote, RustBigUint::from(1u64));
+    assert_eq!(refund, RustBigUint::from(5u64));
+    assert_eq!(kept_dust, RustBigUint::from(0u64));
+}
+
+#[test]
+fn withdraw_without_dust_test() {
+    let relayer = setup();
+
+    assert_err(
+        relayer.withdraw_rounded_down_dust(token_id(), OptionalArg::None),
+        b"No dust to withdraw",
+    );
+}
+
+#[test]
+fn seed_escrowed_balance_test() {
+    let relayer = setup();
+    relayer
+        .accumulated_fees(&token_id())
+        .set(&RustBigUint::from(100u64));
+
+    relayer.seed_escrowed_balance(&token_id(), &RustBigUint::from(1_000u64));
+    assert_eq!(
+        relayer.escrowed_balance(&token_id()).get(),
+        RustBigUint::from(900u64)
+    );
+
+    // an already tracked balance is kept as is
+    relayer.seed_escrowed_balance(&token_id(), &RustBigUint::from(5_000u64));
+    assert_eq!(
+        relayer.escrowed_balance(&token_id()).get(),
+        RustBigUint::from(900u64)
+    );
+}
+
+#[test]
+fn seed_escrowed_balance_only_fees_test() {
+    let relayer = setup();
+    relayer
+        .accumulated_fees(&token_id())
+        .set(&RustBigUint::from(100u64));
+
+    relayer.seed_escrowed_balance(&token_id(), &RustBigUint::from(100u64));
+    assert!(relayer.escrowed_balance(&token_id()).is_empty());
+}
@@ -0,0 +1,49 @@
+use elrond_wasm_debug::RustBigUint;
+
+use transaction_relayer::decimals::scale_amount;
+
+fn scale(amount: u64, from_decimals: u8, to_decimals: u8) -> (RustBigUint, RustBigUint) {
+    scale_amount(&RustBigUint::from(amount), from_decimals, to_decimals)
+}
+
+#[test]
+fn same_decimals_test() {
+    assert_eq!(
+        scale(1_234, 6, 6),
+        (RustBigUint::from(1_234u64), RustBigUint::from(0u64))
+    );
+}
+
+#[test]
+fn scale_up_test() {
+    assert_eq!(
+        scale(1_500_000, 6, 18),
+        (
+            RustBigUint::from(1_500_000_000_000_000_000u64),
+            RustBigUint::from(0u64)
+        )
+    );
+}
+
+#[test]
+fn scale_down_without_dust_test() {
+    assert_eq!(
+        scale(2_000_000_000_000_000, 18, 6),
+        (RustBigUint::from(2_000u64), RustBigUint::from(0u64))
+    );
+}
+
+#[test]
+fn scale_down_with_dust_test() {
+    assert_eq!(
+        scale(1_000_000_000_123, 18, 6),
+        (RustBigUint::from(1u64), RustBigUint::from(123u64))
+    );
+    assert_eq!(
+        scale(999_999_999_999, 18, 6),
+        (
+            RustBigUint::from(0u64),
+            RustBigUint::from(999_999_999_999u64)
+        )
+    );
+}
@@ -0,0 +1,167 @@
+use elrond_wasm::types::{BoxedBytes, SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction::{NftTransactionArgs, TransactionArgs, TransactionStatus};
+use transaction_relayer::*;
+
+const SOURCE_CHAIN_ID: u64 = 5;
+const SOURCE_PROXY: &[u8] = b"tx_relayer_addr_other_chain";
+
+fn raw_tx_args(asset_hash: &[u8]) -> BoxedBytes {
+    TransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(asset_hash),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        amount: RustBigUint::from(1_000u64),
+        from_address: BoxedBytes::from(&b"source_chain_sender"[..]),
+        data: BoxedBytes::empty(),
+    }
+    .to_raw_bytes()
+    .unwrap()
+}
+
+fn raw_nft_tx_args(asset_hash: &[u8]) -> BoxedBytes {
+    NftTransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(asset_hash),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        token_id: RustBigUint::from(1_234u64),
+        amount: RustBigUint::from(1u64),
+        attributes: BoxedBytes::empty(),
+        uris: Vec::new(),
+        from_address: BoxedBytes::from(&b"source_chain_sender"[..]),
+    }
+    .to_raw_bytes()
+    .unwrap()
+}
+
+#[test]
+fn failed_unlock_is_recorded_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+
+    let method_args = raw_tx_args(b"not a token id");
+    match relayer.unlock(
+        method_args.clone(),
+        BoxedBytes::from(SOURCE_PROXY),
+        SOURCE_CHAIN_ID,
+    ) {
+        SCResult::Ok(result) => assert!(result.into_tuple() == (TransactionStatus::Failed, 1)),
+        SCResult::Err(_) => panic!("Failed unlock should have been recorded"),
+    }
+
+    assert!(relayer.failed_unlock_ids().contains(&1));
+    let failed_unlock = relayer.failed_unlock(1).get();
+    assert_eq!(failed_unlock.from_chain_id, SOURCE_CHAIN_ID);
+    assert_eq!(failed_unlock.method_args, method_args);
+    assert!(!failed_unlock.is_nft);
+    assert_eq!(
+        failed_unlock.reason.as_slice(),
+        &b"Invalid Token ID provided"[..]
+    );
+}
+
+#[test]
+fn unlock_from_wrong_proxy_reverts_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+
+    match relayer.unlock(
+        raw_tx_args(b"not a token id"),
+        BoxedBytes::from(&b"some_other_contract"[..]),
+        SOURCE_CHAIN_ID,
+    ) {
+        SCResult::Ok(_) => panic!("Unlock should have been rejected"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"from_contract_address is not the expected proxy contract address"[..]
+        ),
+    }
+    assert!(relayer.failed_unlock_ids().is_empty());
+}
+
+#[test]
+fn unregistered_token_unlock_fails_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+    relayer.chain_assets(SOURCE_CHAIN_ID + 1).insert(
+        TokenIdentifier::from(&b"WEGLD-123456"[..]),
+        BoxedBytes::from(&b"erc20_on_another_chain"[..]),
+    );
+
+    let result = relayer.unlock(
+        raw_tx_args(b"WEGLD-123456"),
+        BoxedBytes::from(SOURCE_PROXY),
+        SOURCE_CHAIN_ID,
+    );
+    assert!(result.is_ok());
+
+    assert_eq!(
+        relayer.failed_unlock(1).get().reason.as_slice(),
+        &b"Token not registered for the source chain"[..]
+    );
+}
+
+#[test]
+fn failed_nft_unlock_is_recorded_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+
+    let method_args = raw_nft_tx_args(b"not a token id");
+    match relayer.unlock_nft(
+        method_args.clone(),
+        BoxedBytes::from(SOURCE_PROXY),
+        SOURCE_CHAIN_ID,
+    ) {
+        SCResult::Ok(result) => assert!(result.into_tuple() == (TransactionStatus::Failed, 1)),
+        SCResult::Err(_) => panic!("Failed unlock should have been recorded"),
+    }
+
+    let failed_unlock = relayer.failed_unlock(1).get();
+    assert_eq!(failed_unlock.method_args, method_args);
+    assert!(failed_unlock.is_nft);
+    assert_eq!(
+        failed_unlock.reason.as_slice(),
+        &b"Invalid Token ID provided"[..]
+    );
+}
+
+#[test]
+fn failed_nft_unlock_retry_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+    let result = relayer.unlock_nft(
+        raw_nft_tx_args(b"NFT-123456"),
+        BoxedBytes::from(SOURCE_PROXY),
+        SOURCE_CHAIN_ID,
+    );
+    assert!(result.is_ok());
+
+    // the retry goes through the NFT unlock again, which still fails
+    match relayer.retry_failed_unlock(1) {
+        SCResult::Ok(_) => panic!("Retry should have failed"),
+        SCResult::Err(err) => assert_eq!(
+            err.as_bytes(),
+            &b"Token not registered for the source chain"[..]
+        ),
+    }
+}
+
+#[test]
+fn wrapped_egld_unwrap_without_token_manager_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+
+    match relayer.require_egld_unwrappable(&RustBigUint::from(1_000u64)) {
+        SCResult::Ok(_) => panic!("Unwrap should have been rejected"),
+        SCResult::Err(err) => {
+            assert_eq!(err.as_bytes(), &b"EsdtTokenManager address not set"[..])
+        }
+    }
+}
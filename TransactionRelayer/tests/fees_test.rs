@@ -0,0 +1,55 @@
+use elrond_wasm::types::{SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::fees::*;
+
+const CHAIN_ID: u64 = 5;
+
+fn token_id() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WEGLD-123456"[..])
+}
+
+#[test]
+fn flat_and_percentage_fee_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .flat_fee(&token_id(), CHAIN_ID)
+        .set(&RustBigUint::from(10u64));
+    relayer.fee_basis_points(&token_id(), CHAIN_ID).set(&25); // 0.25%
+
+    let net_amount = match relayer.take_fee(&token_id(), CHAIN_ID, &RustBigUint::from(10_000u64)) {
+        SCResult::Ok(amount) => amount,
+        SCResult::Err(_) => panic!("Fee should have been taken"),
+    };
+
+    // 10 flat + 25 from the 0.25%
+    assert_eq!(net_amount, RustBigUint::from(9_965u64));
+    assert_eq!(
+        relayer.accumulated_fees(&token_id()).get(),
+        RustBigUint::from(35u64)
+    );
+
+    // fees are per destination chain
+    assert_eq!(
+        relayer.compute_fee(&token_id(), CHAIN_ID + 1, &RustBigUint::from(10_000u64)),
+        RustBigUint::from(0u64)
+    );
+}
+
+#[test]
+fn amount_below_fee_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .flat_fee(&token_id(), CHAIN_ID)
+        .set(&RustBigUint::from(10u64));
+
+    match relayer.take_fee(&token_id(), CHAIN_ID, &RustBigUint::from(10u64)) {
+        SCResult::Ok(_) => panic!("Lock should have been rejected"),
+        SCResult::Err(err) => {
+            assert_eq!(err.as_bytes(), &b"Amount does not cover the bridge fee"[..])
+        }
+    }
+    assert_eq!(
+        relayer.accumulated_fees(&token_id()).get(),
+        RustBigUint::from(0u64)
+    );
+}
@@ -0,0 +1,123 @@
+use access_control::{AccessControlModule, Role};
+use elrond_wasm::types::{Address, BoxedBytes, MultiArg2, SCResult, TokenIdentifier, VarArgs};
+use elrond_wasm_debug::TxContext;
+use transaction_relayer::*;
+
+const CHAIN_ID: u64 = 5;
+const PROXY_HASH: &[u8] = b"tx_relayer_addr_other_chain";
+const USDC_ASSET_HASH: &[u8] = b"usdc_on_another_chain";
+const WETH_ASSET_HASH: &[u8] = b"weth_on_another_chain";
+
+fn usdc() -> TokenIdentifier {
+    TokenIdentifier::from(&b"USDC-123456"[..])
+}
+
+fn weth() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WETH-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn ok<T>(result: SCResult<T>) -> T {
+    match result {
+        SCResult::Ok(value) => value,
+        SCResult::Err(err) => panic!("Call failed: {:?}", err.as_bytes()),
+    }
+}
+
+/// Storage as left by a relayer released before the chain and token registries
+fn setup_legacy_storage() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .legacy_proxy_hash_map(CHAIN_ID)
+        .set(&BoxedBytes::from(PROXY_HASH));
+    relayer
+        .legacy_asset_hash_map(&usdc(), CHAIN_ID)
+        .set(&BoxedBytes::from(USDC_ASSET_HASH));
+    relayer
+        .legacy_asset_hash_map(&weth(), CHAIN_ID)
+        .set(&BoxedBytes::from(WETH_ASSET_HASH));
+
+    relayer
+}
+
+#[test]
+fn migrate_legacy_registries_test() {
+    let relayer = setup_legacy_storage();
+
+    assert!(relayer
+        .migrate_storage(&[(CHAIN_ID, usdc()), (CHAIN_ID, weth())])
+        .is_ok());
+    assert_eq!(relayer.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+    assert_eq!(
+        ok(relayer.get_chain_proxy_hash(CHAIN_ID)),
+        BoxedBytes::from(PROXY_HASH)
+    );
+    assert_eq!(
+        ok(relayer.get_token_asset_hash(usdc(), CHAIN_ID)),
+        BoxedBytes::from(USDC_ASSET_HASH)
+    );
+    assert_eq!(
+        ok(relayer.get_token_asset_hash(weth(), CHAIN_ID)),
+        BoxedBytes::from(WETH_ASSET_HASH)
+    );
+    assert_eq!(
+        ok(relayer.get_token_for_asset_hash(CHAIN_ID, BoxedBytes::from(WETH_ASSET_HASH))),
+        weth()
+    );
+
+    // the legacy keys are cleared once copied
+    assert!(relayer.legacy_proxy_hash_map(CHAIN_ID).is_empty());
+    assert!(relayer.legacy_asset_hash_map(&usdc(), CHAIN_ID).is_empty());
+    assert!(relayer.legacy_asset_hash_map(&weth(), CHAIN_ID).is_empty());
+}
+
+#[test]
+fn migrate_unlisted_legacy_asset_test() {
+    let relayer = setup_legacy_storage();
+    relayer.role_members(Role::Admin).insert(Address::zero());
+
+    assert!(relayer.migrate_storage(&[(CHAIN_ID, usdc())]).is_ok());
+    assert_err(
+        relayer.get_token_asset_hash(weth(), CHAIN_ID),
+        b"Token not registered for the chain",
+    );
+
+    // pairs left out on upgrade are copied afterwards
+    assert!(relayer
+        .migrate_legacy_assets_endpoint(VarArgs::from(vec![MultiArg2::from((CHAIN_ID, weth()))]))
+        .is_ok());
+    assert_eq!(
+        ok(relayer.get_token_asset_hash(weth(), CHAIN_ID)),
+        BoxedBytes::from(WETH_ASSET_HASH)
+    );
+}
+
+#[test]
+fn migrate_missing_legacy_asset_test() {
+    let relayer = setup_legacy_storage();
+
+    assert_err(
+        relayer.migrate_storage(&[(CHAIN_ID, TokenIdentifier::from(&b"WBTC-123456"[..]))]),
+        b"No legacy asset hash for the token",
+    );
+}
+
+#[test]
+fn migrate_legacy_asset_without_chain_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .legacy_asset_hash_map(&usdc(), CHAIN_ID)
+        .set(&BoxedBytes::from(USDC_ASSET_HASH));
+
+    assert_err(
+        relayer.migrate_storage(&[(CHAIN_ID, usdc())]),
+        b"Chain not supported",
+    );
+}
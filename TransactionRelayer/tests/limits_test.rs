@@ -0,0 +1,84 @@
+use elrond_wasm::types::{SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::limits::*;
+
+const CHAIN_ID: u64 = 5;
+
+fn token_id() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WEGLD-123456"[..])
+}
+
+fn assert_err(result: SCResult<()>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Transfer should have been rejected"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+#[test]
+fn min_max_amount_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .min_transfer_amount(&token_id(), CHAIN_ID)
+        .set(&RustBigUint::from(10u64));
+    relayer
+        .max_transfer_amount(&token_id(), CHAIN_ID)
+        .set(&RustBigUint::from(100u64));
+
+    assert_err(
+        relayer.check_transfer_limits(
+            &token_id(),
+            CHAIN_ID,
+            TransferDirection::Outbound,
+            &RustBigUint::from(9u64),
+        ),
+        b"Amount below the minimum transfer amount",
+    );
+    assert_err(
+        relayer.check_transfer_limits(
+            &token_id(),
+            CHAIN_ID,
+            TransferDirection::Inbound,
+            &RustBigUint::from(101u64),
+        ),
+        b"Amount above the maximum transfer amount",
+    );
+
+    // the minimum is not enforced for inbound transfers
+    assert!(relayer
+        .check_transfer_limits(
+            &token_id(),
+            CHAIN_ID,
+            TransferDirection::Inbound,
+            &RustBigUint::from(9u64),
+        )
+        .is_ok());
+}
+
+#[test]
+fn daily_cap_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .daily_cap(&token_id(), CHAIN_ID, TransferDirection::Outbound)
+        .set(&RustBigUint::from(1_000u64));
+
+    let amount = RustBigUint::from(600u64);
+    assert!(relayer
+        .check_transfer_limits(&token_id(), CHAIN_ID, TransferDirection::Outbound, &amount)
+        .is_ok());
+    relayer.record_transfer_volume(&token_id(), CHAIN_ID, TransferDirection::Outbound, &amount);
+
+    match relayer.get_remaining_daily_capacity(token_id(), CHAIN_ID, TransferDirection::Outbound) {
+        SCResult::Ok(remaining) => assert_eq!(remaining, RustBigUint::from(400u64)),
+        SCResult::Err(_) => panic!("Daily cap should be set"),
+    }
+    assert_err(
+        relayer.check_transfer_limits(&token_id(), CHAIN_ID, TransferDirection::Outbound, &amount),
+        b"Daily outbound cap exceeded",
+    );
+
+    // inbound volume is tracked separately, and not capped
+    assert!(relayer
+        .check_transfer_limits(&token_id(), CHAIN_ID, TransferDirection::Inbound, &amount)
+        .is_ok());
+}
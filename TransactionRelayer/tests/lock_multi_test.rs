@@ -0,0 +1,149 @@
+use elrond_wasm::types::{Address, SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::fees::FeesModule;
+use transaction_relayer::limits::LimitsModule;
+use transaction_relayer::*;
+
+const CHAIN_ID: u64 = 5;
+
+fn usdc() -> TokenIdentifier {
+    TokenIdentifier::from(&b"USDC-123456"[..])
+}
+
+fn weth() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WETH-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn ok<T>(result: SCResult<T>) -> T {
+    match result {
+        SCResult::Ok(value) => value,
+        SCResult::Err(err) => panic!("Call failed: {:?}", err.as_bytes()),
+    }
+}
+
+/// Both tokens are held in custody, as burning needs the local roles of a real account.
+/// USDC pays a flat fee, WETH a 1% fee and has a maximum transfer amount.
+fn setup() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer.token_mode(&usdc()).set(&TokenMode::Custody);
+    relayer.token_mode(&weth()).set(&TokenMode::Custody);
+
+    relayer
+        .flat_fee(&usdc(), CHAIN_ID)
+        .set(&RustBigUint::from(10u64));
+    relayer.fee_basis_points(&weth(), CHAIN_ID).set(&100);
+    relayer
+        .max_transfer_amount(&weth(), CHAIN_ID)
+        .set(&RustBigUint::from(500_000u64));
+
+    relayer
+}
+
+fn take_locked_tokens(
+    relayer: &impl TransactionRelayer,
+    token_id: &TokenIdentifier,
+    amount: u64,
+) -> SCResult<(RustBigUint, RustBigUint)> {
+    relayer.take_locked_tokens(
+        &Address::zero(),
+        token_id,
+        CHAIN_ID,
+        &RustBigUint::from(amount),
+    )
+}
+
+#[test]
+fn transfer_count_test() {
+    let relayer = setup();
+
+    assert_err(
+        relayer.require_lock_multi_transfer_count(0),
+        b"No tokens provided",
+    );
+    assert_err(
+        relayer.require_lock_multi_transfer_count(MAX_LOCK_MULTI_TRANSFERS + 1),
+        b"Too many tokens in a single transfer",
+    );
+    assert!(relayer
+        .require_lock_multi_transfer_count(MAX_LOCK_MULTI_TRANSFERS)
+        .is_ok());
+}
+
+#[test]
+fn each_token_pays_its_own_fee_test() {
+    let relayer = setup();
+
+    assert_eq!(
+        ok(take_locked_tokens(&relayer, &usdc(), 1_000)),
+        (RustBigUint::from(990u64), RustBigUint::from(990u64))
+    );
+    assert_eq!(
+        ok(take_locked_tokens(&relayer, &weth(), 10_000)),
+        (RustBigUint::from(9_900u64), RustBigUint::from(9_900u64))
+    );
+
+    assert_eq!(
+        relayer.accumulated_fees(&usdc()).get(),
+        RustBigUint::from(10u64)
+    );
+    assert_eq!(
+        relayer.accumulated_fees(&weth()).get(),
+        RustBigUint::from(100u64)
+    );
+    assert_eq!(
+        relayer.escrowed_balance(&usdc()).get(),
+        RustBigUint::from(990u64)
+    );
+    assert_eq!(
+        relayer.escrowed_balance(&weth()).get(),
+        RustBigUint::from(9_900u64)
+    );
+}
+
+#[test]
+fn flat_fee_for_each_transfer_test() {
+    let relayer = setup();
+
+    for _ in 0..MAX_LOCK_MULTI_TRANSFERS {
+        assert!(take_locked_tokens(&relayer, &usdc(), 100).is_ok());
+    }
+
+    assert_eq!(
+        relayer.accumulated_fees(&usdc()).get(),
+        RustBigUint::from(100u64)
+    );
+    assert_eq!(
+        relayer.escrowed_balance(&usdc()).get(),
+        RustBigUint::from(900u64)
+    );
+}
+
+#[test]
+fn above_max_amount_test() {
+    let relayer = setup();
+
+    // the limit applies to the amount left after the fee
+    assert_err(
+        take_locked_tokens(&relayer, &weth(), 600_000),
+        b"Amount above the maximum transfer amount",
+    );
+    assert!(relayer.escrowed_balance(&weth()).is_empty());
+}
+
+#[test]
+fn amount_below_fee_test() {
+    let relayer = setup();
+
+    assert_err(
+        take_locked_tokens(&relayer, &usdc(), 10),
+        b"Amount does not cover the bridge fee",
+    );
+    assert!(relayer.escrowed_balance(&usdc()).is_empty());
+}
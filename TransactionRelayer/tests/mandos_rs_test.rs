@@ -0,0 +1,15 @@
+use elrond_wasm_debug::*;
+
+fn contract_map() -> ContractMap<TxContext> {
+    let mut contract_map = ContractMap::new();
+    contract_map.register_contract(
+        "file:../output/transaction-relayer.wasm",
+        Box::new(|context| Box::new(transaction_relayer::contract_obj(context))),
+    );
+    contract_map
+}
+
+#[test]
+fn migrate_storage_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/migrate_storage.scen.json", &contract_map());
+}
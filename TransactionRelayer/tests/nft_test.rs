@@ -0,0 +1,200 @@
+use elrond_wasm::types::{SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::*;
+
+const NONCE: u64 = 3;
+const FOREIGN_TOKEN_ID: u64 = 1_234;
+
+/// A collection created by the bridge, for NFTs coming from other chains
+fn bridged_collection() -> TokenIdentifier {
+    TokenIdentifier::from(&b"BRIDGED-123456"[..])
+}
+
+/// A collection issued on Elrond, whose NFTs are held in custody while on other chains
+fn custody_collection() -> TokenIdentifier {
+    TokenIdentifier::from(&b"NATIVE-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn ok<T>(result: SCResult<T>) -> T {
+    match result {
+        SCResult::Ok(value) => value,
+        SCResult::Err(err) => panic!("Call failed: {:?}", err.as_bytes()),
+    }
+}
+
+fn setup() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .token_mode(&custody_collection())
+        .set(&TokenMode::Custody);
+
+    relayer
+}
+
+fn big(value: u64) -> RustBigUint {
+    RustBigUint::from(value)
+}
+
+#[test]
+fn lock_custody_nft_maps_nonce_test() {
+    let relayer = setup();
+
+    // the nonce is used as ID on the other chains
+    assert_eq!(
+        ok(relayer.escrow_locked_nft(&custody_collection(), NONCE, &big(1))),
+        big(NONCE)
+    );
+    assert_eq!(
+        relayer.nft_nonce(&custody_collection(), &big(NONCE)).get(),
+        NONCE
+    );
+    assert_eq!(
+        relayer
+            .escrowed_nft_balance(&custody_collection(), NONCE)
+            .get(),
+        big(1)
+    );
+}
+
+#[test]
+fn lock_bridged_nft_keeps_foreign_id_test() {
+    let relayer = setup();
+    relayer.set_nft_token_id_mapping(&bridged_collection(), NONCE, &big(FOREIGN_TOKEN_ID));
+
+    assert_eq!(
+        ok(relayer.escrow_locked_nft(&bridged_collection(), NONCE, &big(2))),
+        big(FOREIGN_TOKEN_ID)
+    );
+    assert_eq!(
+        relayer
+            .escrowed_nft_balance(&bridged_collection(), NONCE)
+            .get(),
+        big(2)
+    );
+}
+
+#[test]
+fn lock_nft_not_created_by_bridge_test() {
+    let relayer = setup();
+
+    assert_err(
+        relayer.escrow_locked_nft(&bridged_collection(), NONCE, &big(1)),
+        b"Token was not created by the bridge",
+    );
+    assert!(relayer
+        .escrowed_nft_balance(&bridged_collection(), NONCE)
+        .is_empty());
+}
+
+#[test]
+fn unlock_releases_escrowed_nft_test() {
+    let relayer = setup();
+    relayer.set_nft_token_id_mapping(&bridged_collection(), NONCE, &big(FOREIGN_TOKEN_ID));
+    assert!(relayer
+        .escrow_locked_nft(&bridged_collection(), NONCE, &big(5))
+        .is_ok());
+
+    assert_eq!(
+        ok(relayer.try_release_unlocked_nft(
+            &bridged_collection(),
+            &big(FOREIGN_TOKEN_ID),
+            &big(3)
+        )),
+        Some(NONCE)
+    );
+    assert_eq!(
+        relayer
+            .escrowed_nft_balance(&bridged_collection(), NONCE)
+            .get(),
+        big(2)
+    );
+}
+
+#[test]
+fn unlock_mints_missing_bridged_nft_test() {
+    let relayer = setup();
+    relayer.set_nft_token_id_mapping(&bridged_collection(), NONCE, &big(FOREIGN_TOKEN_ID));
+    assert!(relayer
+        .escrow_locked_nft(&bridged_collection(), NONCE, &big(1))
+        .is_ok());
+
+    // not enough escrowed, so more are minted, and the escrowed ones are kept
+    assert_eq!(
+        ok(relayer.try_release_unlocked_nft(
+            &bridged_collection(),
+            &big(FOREIGN_TOKEN_ID),
+            &big(3)
+        )),
+        None
+    );
+    assert_eq!(
+        relayer
+            .escrowed_nft_balance(&bridged_collection(), NONCE)
+            .get(),
+        big(1)
+    );
+}
+
+#[test]
+fn unlock_creates_unknown_bridged_nft_test() {
+    let relayer = setup();
+
+    assert_eq!(
+        ok(relayer.try_release_unlocked_nft(
+            &bridged_collection(),
+            &big(FOREIGN_TOKEN_ID),
+            &big(1)
+        )),
+        None
+    );
+}
+
+#[test]
+fn unlock_custody_nft_test() {
+    let relayer = setup();
+    assert!(relayer
+        .escrow_locked_nft(&custody_collection(), NONCE, &big(1))
+        .is_ok());
+
+    assert_eq!(
+        ok(relayer.try_release_unlocked_nft(&custody_collection(), &big(NONCE), &big(1))),
+        Some(NONCE)
+    );
+    assert_eq!(
+        relayer
+            .escrowed_nft_balance(&custody_collection(), NONCE)
+            .get(),
+        big(0)
+    );
+}
+
+#[test]
+fn unlock_custody_nft_not_in_escrow_test() {
+    let relayer = setup();
+    assert!(relayer
+        .escrow_locked_nft(&custody_collection(), NONCE, &big(1))
+        .is_ok());
+
+    // custody collections are never minted
+    assert_err(
+        relayer.try_release_unlocked_nft(&custody_collection(), &big(NONCE), &big(2)),
+        b"Not enough tokens in escrow",
+    );
+}
+
+#[test]
+fn unlock_unknown_custody_nft_test() {
+    let relayer = setup();
+
+    assert_err(
+        relayer.try_release_unlocked_nft(&custody_collection(), &big(NONCE), &big(1)),
+        b"Unknown token for a custody collection",
+    );
+}
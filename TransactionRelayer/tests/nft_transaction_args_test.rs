@@ -0,0 +1,67 @@
+use elrond_wasm::types::BoxedBytes;
+use elrond_wasm_debug::RustBigUint;
+use transaction::NftTransactionArgs;
+
+#[test]
+fn raw_bytes_round_trip_test() {
+    let args = NftTransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(&b"NFT-123456"[..]),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        token_id: RustBigUint::from(1_234u64),
+        amount: RustBigUint::from(5u64),
+        attributes: BoxedBytes::from(&b"some attributes"[..]),
+        uris: vec![
+            BoxedBytes::from(&b"https://first.uri"[..]),
+            BoxedBytes::from(&b"https://second.uri"[..]),
+        ],
+        from_address: BoxedBytes::empty(),
+    };
+
+    let raw_args = args.to_raw_bytes().unwrap();
+    let decoded = NftTransactionArgs::<RustBigUint>::from_raw_bytes(raw_args.as_slice()).unwrap();
+
+    assert_eq!(decoded.asset_hash, args.asset_hash);
+    assert_eq!(decoded.dest_address, args.dest_address);
+    assert_eq!(decoded.token_id, args.token_id);
+    assert_eq!(decoded.amount, args.amount);
+    assert_eq!(decoded.attributes, args.attributes);
+    assert_eq!(decoded.uris, args.uris);
+    assert!(decoded.from_address.is_empty());
+}
+
+#[test]
+fn truncated_uris_test() {
+    let args = NftTransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(&b"NFT-123456"[..]),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        token_id: RustBigUint::from(1u64),
+        amount: RustBigUint::from(1u64),
+        attributes: BoxedBytes::empty(),
+        uris: vec![BoxedBytes::from(&b"https://first.uri"[..])],
+        from_address: BoxedBytes::empty(),
+    };
+
+    let raw_args = args.to_raw_bytes().unwrap();
+    let truncated = &raw_args.as_slice()[..raw_args.len() - 1];
+
+    assert!(NftTransactionArgs::<RustBigUint>::from_raw_bytes(truncated).is_err());
+}
+
+#[test]
+fn from_address_round_trip_test() {
+    let args = NftTransactionArgs::<RustBigUint> {
+        asset_hash: BoxedBytes::from(&b"NFT-123456"[..]),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        token_id: RustBigUint::from(1u64),
+        amount: RustBigUint::from(1u64),
+        attributes: BoxedBytes::empty(),
+        uris: Vec::new(),
+        from_address: BoxedBytes::from(&b"source_chain_sender"[..]),
+    };
+
+    let raw_args = args.to_raw_bytes().unwrap();
+    let decoded = NftTransactionArgs::<RustBigUint>::from_raw_bytes(raw_args.as_slice()).unwrap();
+
+    assert!(decoded.uris.is_empty());
+    assert_eq!(decoded.from_address, args.from_address);
+}
@@ -0,0 +1,58 @@
+use elrond_wasm::types::{SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::*;
+
+fn wrapped_egld_token_id() -> TokenIdentifier {
+    TokenIdentifier::from(&b"WEGLD-123456"[..])
+}
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+#[test]
+fn migrate_from_unversioned_storage_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+
+    // no wrapped eGLD set, so there is nothing to seed
+    assert!(relayer.migrate_storage(&[]).is_ok());
+    assert_eq!(relayer.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+    // running the migrations again is a no-op
+    assert!(relayer.migrate_storage(&[]).is_ok());
+    assert_eq!(relayer.storage_version().get(), CURRENT_STORAGE_VERSION);
+}
+
+#[test]
+fn migrate_keeps_tracked_wrapped_egld_escrow_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .wrapped_egld_token_id()
+        .set(&wrapped_egld_token_id());
+    relayer
+        .escrowed_balance(&wrapped_egld_token_id())
+        .set(&RustBigUint::from(500u64));
+
+    assert!(relayer.migrate_storage(&[]).is_ok());
+    assert_eq!(
+        relayer.escrowed_balance(&wrapped_egld_token_id()).get(),
+        RustBigUint::from(500u64)
+    );
+    assert_eq!(relayer.storage_version().get(), CURRENT_STORAGE_VERSION);
+}
+
+#[test]
+fn newer_storage_version_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .storage_version()
+        .set(&(CURRENT_STORAGE_VERSION + 1));
+
+    assert_err(
+        relayer.migrate_storage(&[]),
+        b"Storage version is newer than the contract code",
+    );
+}
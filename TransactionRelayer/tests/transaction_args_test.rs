@@ -0,0 +1,53 @@
+use elrond_wasm::elrond_codec::{TopDecode, TopEncode};
+use elrond_wasm::types::BoxedBytes;
+use elrond_wasm_debug::RustBigUint;
+use transaction::TransactionArgs;
+
+fn tx_args(from_address: &[u8], data: &[u8]) -> TransactionArgs<RustBigUint> {
+    TransactionArgs {
+        asset_hash: BoxedBytes::from(&b"WEGLD-123456"[..]),
+        dest_address: BoxedBytes::from(&[3u8; 32][..]),
+        amount: RustBigUint::from(1_000u64),
+        from_address: BoxedBytes::from(from_address),
+        data: BoxedBytes::from(data),
+    }
+}
+
+#[test]
+fn without_optional_fields_test() {
+    let args = tx_args(b"", b"");
+    let raw_args = args.to_raw_bytes().unwrap();
+
+    // 1-byte length + 12 bytes asset hash, 1-byte length + 32 bytes address, 32 bytes amount
+    assert_eq!(raw_args.len(), 13 + 33 + 32);
+
+    let decoded = TransactionArgs::<RustBigUint>::from_raw_bytes(raw_args.as_slice()).unwrap();
+    assert_eq!(decoded.amount, args.amount);
+    assert!(decoded.from_address.is_empty());
+    assert!(decoded.data.is_empty());
+}
+
+#[test]
+fn with_optional_fields_test() {
+    let args = tx_args(b"some_sender", b"stake");
+    let raw_args = args.to_raw_bytes().unwrap();
+
+    let decoded = TransactionArgs::<RustBigUint>::from_raw_bytes(raw_args.as_slice()).unwrap();
+    assert_eq!(decoded.asset_hash, args.asset_hash);
+    assert_eq!(decoded.dest_address, args.dest_address);
+    assert_eq!(decoded.amount, args.amount);
+    assert_eq!(decoded.from_address, args.from_address);
+    assert_eq!(decoded.data, args.data);
+}
+
+#[test]
+fn top_encode_round_trip_test() {
+    let args = tx_args(b"", b"stake");
+
+    let mut encoded = Vec::new();
+    args.top_encode(&mut encoded).unwrap();
+    let decoded = TransactionArgs::<RustBigUint>::top_decode(encoded.as_slice()).unwrap();
+
+    assert!(decoded.from_address.is_empty());
+    assert_eq!(decoded.data, args.data);
+}
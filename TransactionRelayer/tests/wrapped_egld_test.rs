@@ -0,0 +1,101 @@
+use access_control::{AccessControlModule, Role};
+use elrond_wasm::types::{Address, BoxedBytes, SCResult, TokenIdentifier};
+use elrond_wasm_debug::{RustBigUint, TxContext};
+use transaction_relayer::*;
+
+const SOURCE_CHAIN_ID: u64 = 5;
+const SOURCE_PROXY: &[u8] = b"tx_relayer_addr_other_chain";
+const WRAPPED_EGLD_TOKEN_ID: &[u8] = b"WEGLD-123456";
+
+fn assert_err<T>(result: SCResult<T>, expected_err: &[u8]) {
+    match result {
+        SCResult::Ok(_) => panic!("Call should have failed"),
+        SCResult::Err(err) => assert_eq!(err.as_bytes(), expected_err),
+    }
+}
+
+fn wrapped_egld() -> TokenIdentifier {
+    TokenIdentifier::from(WRAPPED_EGLD_TOKEN_ID)
+}
+
+/// The token manager calls cannot run here, so its address and token ID are set directly
+fn setup() -> impl TransactionRelayer {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+    relayer
+        .supported_chains()
+        .insert(SOURCE_CHAIN_ID, BoxedBytes::from(SOURCE_PROXY));
+    relayer.chain_assets(SOURCE_CHAIN_ID).insert(
+        wrapped_egld(),
+        BoxedBytes::from(&b"wegld_on_another_chain"[..]),
+    );
+    relayer.wrapped_egld_token_id().set(&wrapped_egld());
+
+    relayer
+}
+
+#[test]
+fn lock_egld_without_token_manager_test() {
+    let relayer = transaction_relayer::contract_obj(TxContext::dummy());
+
+    assert_err(
+        relayer.lock_egld(
+            RustBigUint::from(1_000u64),
+            SOURCE_CHAIN_ID,
+            BoxedBytes::from(&[3u8; 32][..]),
+        ),
+        b"EsdtTokenManager address not set",
+    );
+}
+
+#[test]
+fn lock_egld_zero_test() {
+    let relayer = setup();
+    relayer
+        .egld_token_manager_address()
+        .set(&Address::from([4u8; 32]));
+
+    assert_err(
+        relayer.lock_egld(
+            RustBigUint::from(0u64),
+            SOURCE_CHAIN_ID,
+            BoxedBytes::from(&[3u8; 32][..]),
+        ),
+        b"Must bridge more than 0 tokens",
+    );
+}
+
+#[test]
+fn wrapped_egld_mode_cannot_change_test() {
+    let relayer = setup();
+    relayer
+        .role_members(Role::AssetManager)
+        .insert(Address::zero());
+
+    assert_err(
+        relayer.set_token_mode(wrapped_egld(), TokenMode::MintBurn),
+        b"Wrapped eGLD is always held in custody",
+    );
+}
+
+#[test]
+fn wrapped_egld_unlock_is_released_from_escrow_test() {
+    let relayer = setup();
+    assert!(relayer.is_custody_token(&wrapped_egld()));
+
+    // wrapped eGLD is never minted on unlock, it's only unwrapped from what was locked
+    assert_err(
+        relayer.release_from_escrow(&wrapped_egld(), &RustBigUint::from(1_000u64)),
+        b"Not enough tokens in escrow",
+    );
+
+    relayer
+        .escrowed_balance(&wrapped_egld())
+        .set(&RustBigUint::from(1_000u64));
+    assert!(relayer
+        .release_from_escrow(&wrapped_egld(), &RustBigUint::from(400u64))
+        .is_ok());
+    assert_eq!(
+        relayer.escrowed_balance(&wrapped_egld()).get(),
+        RustBigUint::from(600u64)
+    );
+}
@@ -0,0 +1,105 @@
+#![no_std]
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum Role {
+    /// Critical configuration (consensus, contract addresses, upgrades) and role management
+    Admin,
+    /// Routine asset and chain mappings
+    AssetManager,
+    /// Can pause the contracts, but not unpause them
+    Pauser,
+    /// Off-chain relayers, reporting the status of cross-chain transactions
+    Relayer,
+    /// Can withdraw the collected bridge fees
+    FeeCollector,
+}
+
+/// Roles granted on top of the owner, who implicitly holds all of them
+#[elrond_wasm_derive::module]
+pub trait AccessControlModule {
+    // endpoints - admin-only
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.role_members(role).insert(address.clone()),
+            "Address already has the role"
+        );
+
+        self.role_granted_event(role, &address);
+
+        Ok(())
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: Address) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            self.role_members(role).remove(&address),
+            "Address does not have the role"
+        );
+
+        self.role_revoked_event(role, &address);
+
+        Ok(())
+    }
+
+    // endpoints
+
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: Role) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(role).remove(&caller),
+            "Caller does not have the role"
+        );
+
+        self.role_revoked_event(role, &caller);
+
+        Ok(())
+    }
+
+    // views
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: Address) -> bool {
+        self.role_members(role).contains(&address)
+            || address == self.blockchain().get_owner_address()
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: Role) -> MultiResultVec<Address> {
+        self.role_members(role)
+            .iter()
+            .collect::<Vec<Address>>()
+            .into()
+    }
+
+    // private
+
+    fn require_role(&self, role: Role) -> SCResult<()> {
+        require!(
+            self.has_role(role, self.blockchain().get_caller()),
+            "Caller does not have the required role"
+        );
+
+        Ok(())
+    }
+
+    // events
+
+    #[event("roleGranted")]
+    fn role_granted_event(&self, #[indexed] role: Role, address: &Address);
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(&self, #[indexed] role: Role, address: &Address);
+
+    // storage
+
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: Role) -> SetMapper<Self::Storage, Address>;
+}
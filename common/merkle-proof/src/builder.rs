@@ -0,0 +1,135 @@
+use crate::{hash_children, hash_leaf, MerkleProof, MerkleProofNode, MerkleProofNodePosition};
+use elrond_wasm::api::CryptoApi;
+use elrond_wasm::types::{BoxedBytes, Vec, H256};
+
+/// Off-chain builder for Poly-compatible merkle trees.
+/// The tree layout is the one used by Poly (RFC 6962): a range of `n` leaves is split
+/// into the largest power of two smaller than `n` on the left, and the remaining leaves on the right.
+/// Also used to generate proofs against the root of a `MerkleMountainRange` with the same leaves.
+pub struct MerkleTreeBuilder<CA>
+where
+    CA: CryptoApi + Clone,
+{
+    api: CA,
+    leaves: Vec<BoxedBytes>,
+}
+
+impl<CA: CryptoApi + Clone> MerkleTreeBuilder<CA> {
+    pub fn new(api: CA) -> Self {
+        Self {
+            api,
+            leaves: Vec::new(),
+        }
+    }
+
+    pub fn with_leaves(api: CA, leaves: Vec<BoxedBytes>) -> Self {
+        Self { api, leaves }
+    }
+
+    pub fn add_leaf(&mut self, raw_leaf: &[u8]) -> &mut Self {
+        self.leaves.push(BoxedBytes::from(raw_leaf));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Root of the tree. An empty tree has the zero hash as root.
+    pub fn root(&self) -> H256 {
+        if self.leaves.is_empty() {
+            return H256::zero();
+        }
+
+        self.subtree_root(0, self.leaves.len())
+    }
+
+    /// Proof for the leaf at `index`, or `None` if the index is out of range
+    pub fn proof(&self, index: usize) -> Option<MerkleProof<CA>> {
+        if index >= self.leaves.len() {
+            return None;
+        }
+
+        let mut nodes = Vec::new();
+        self.collect_path(index, 0, self.leaves.len(), &mut nodes);
+
+        Some(MerkleProof::new(
+            self.api.clone(),
+            self.leaves[index].clone(),
+            nodes,
+        ))
+    }
+
+    /// Proof for the leaf at `index`, serialized in the format `MerkleProof::from_bytes` expects
+    pub fn serialized_proof(&self, index: usize) -> Option<BoxedBytes> {
+        self.proof(index).map(|proof| proof.to_bytes())
+    }
+
+    /// Serialized proofs for each of the given leaves, in the same order.
+    /// Returns `None` if any of the indexes is out of range.
+    pub fn serialized_multi_proof(&self, indexes: &[usize]) -> Option<Vec<BoxedBytes>> {
+        let mut proofs = Vec::with_capacity(indexes.len());
+        for index in indexes {
+            proofs.push(self.serialized_proof(*index)?);
+        }
+
+        Some(proofs)
+    }
+
+    // private
+
+    fn subtree_root(&self, start: usize, end: usize) -> H256 {
+        let width = end - start;
+        if width == 1 {
+            return hash_leaf(&self.api, self.leaves[start].as_slice());
+        }
+
+        let split = start + split_width(width);
+        let left = self.subtree_root(start, split);
+        let right = self.subtree_root(split, end);
+
+        hash_children(&self.api, &left, &right)
+    }
+
+    fn collect_path(
+        &self,
+        index: usize,
+        start: usize,
+        end: usize,
+        nodes: &mut Vec<MerkleProofNode>,
+    ) {
+        let width = end - start;
+        if width == 1 {
+            return;
+        }
+
+        let split = start + split_width(width);
+        if index < split {
+            self.collect_path(index, start, split, nodes);
+            nodes.push(MerkleProofNode {
+                position: MerkleProofNodePosition::Right,
+                hash: self.subtree_root(split, end),
+            });
+        } else {
+            self.collect_path(index, split, end, nodes);
+            nodes.push(MerkleProofNode {
+                position: MerkleProofNodePosition::Left,
+                hash: self.subtree_root(start, split),
+            });
+        }
+    }
+}
+
+/// largest power of two strictly smaller than width (width must be at least 2)
+fn split_width(width: usize) -> usize {
+    let mut split = 1;
+    while split << 1 < width {
+        split <<= 1;
+    }
+
+    split
+}
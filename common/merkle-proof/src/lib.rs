@@ -1,12 +1,18 @@
 #![no_std]
 
-use elrond_wasm::types::{BoxedBytes, H256};
+use elrond_wasm::api::CryptoApi;
+use elrond_wasm::types::{BoxedBytes, SCError, Vec, H256};
+use zero_copy_sink::ZeroCopySink;
 use zero_copy_source::ZeroCopySource;
 
-elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
 
-#[derive(TypeAbi, PartialEq)]
+pub mod mmr;
+
+#[cfg(feature = "std")]
+pub mod builder;
+
+#[derive(TypeAbi, PartialEq, Clone, Copy)]
 pub enum MerkleProofNodePosition {
     Left,
     Right,
@@ -20,16 +26,53 @@ impl MerkleProofNodePosition {
         }
     }
 
-    fn from_u8(val: u8) -> SCResult<Self> {
+    fn from_u8(val: u8) -> Option<Self> {
         match val {
-            0 => Ok(Self::Left),
-            1 => Ok(Self::Right),
-            _ => sc_error!("Failed From<u8> for MerkleProofNodePosition"),
+            0 => Some(Self::Left),
+            1 => Some(Self::Right),
+            _ => None,
         }
     }
 }
 
-#[derive(TypeAbi)]
+/// Maximum number of nodes accepted in a proof, i.e. the maximum tree height
+pub const MAX_MERKLE_PROOF_DEPTH: usize = 32;
+
+/// One position byte followed by a 32-byte hash
+const NODE_SERIALIZED_LEN: usize = 1 + 32;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MerkleProofError {
+    TruncatedLeaf,
+    InvalidNodePosition,
+    TruncatedHash,
+    TrailingBytes,
+    MaxDepthExceeded,
+}
+
+impl MerkleProofError {
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match *self {
+            Self::TruncatedLeaf => b"Merkle Proof deserialization failed: Raw leaf is truncated",
+            Self::InvalidNodePosition => {
+                b"Merkle Proof deserialization failed: Invalid node position byte"
+            }
+            Self::TruncatedHash => b"Merkle Proof deserialization failed: Node hash is truncated",
+            Self::TrailingBytes => b"Merkle Proof deserialization failed: Trailing bytes",
+            Self::MaxDepthExceeded => {
+                b"Merkle Proof deserialization failed: Maximum proof depth exceeded"
+            }
+        }
+    }
+}
+
+impl From<MerkleProofError> for SCError {
+    fn from(err: MerkleProofError) -> Self {
+        SCError::from(err.as_bytes())
+    }
+}
+
+#[derive(TypeAbi, Clone)]
 pub struct MerkleProofNode {
     pub position: MerkleProofNodePosition,
     pub hash: H256,
@@ -45,51 +88,85 @@ where
 }
 
 impl<CA: CryptoApi> MerkleProof<CA> {
-    pub fn from_bytes(api: CA, proof_bytes: &BoxedBytes) -> SCResult<Self> {
+    pub fn new(api: CA, raw_leaf: BoxedBytes, nodes: Vec<MerkleProofNode>) -> Self {
+        Self {
+            api,
+            raw_leaf,
+            nodes,
+        }
+    }
+
+    pub fn from_bytes(api: CA, proof_bytes: &BoxedBytes) -> Result<Self, MerkleProofError> {
         let mut source = ZeroCopySource::new(proof_bytes.as_slice());
         let raw_leaf;
         let mut nodes = Vec::new();
 
         match source.next_var_bytes() {
             Some(leaf) => raw_leaf = leaf,
-            None => return sc_error!("Merkle Proof deserialization failed: Raw leaf"),
+            None => return Err(MerkleProofError::TruncatedLeaf),
         }
 
-        while source.get_bytes_left() > 0 {
+        while source.get_bytes_left() >= NODE_SERIALIZED_LEN {
+            if nodes.len() == MAX_MERKLE_PROOF_DEPTH {
+                return Err(MerkleProofError::MaxDepthExceeded);
+            }
+
             let position;
             let hash;
 
-            match source.next_u8() {
-                Some(pos) => position = MerkleProofNodePosition::from_u8(pos)?,
-                None => return sc_error!("Merkle Proof deserialization failed: Pos"),
+            match source.next_u8().and_then(MerkleProofNodePosition::from_u8) {
+                Some(pos) => position = pos,
+                None => return Err(MerkleProofError::InvalidNodePosition),
             }
             match source.next_hash() {
                 Some(h) => hash = h,
-                None => return sc_error!("Merkle Proof deserialization failed: Hash"),
+                None => return Err(MerkleProofError::TruncatedHash),
             }
 
             nodes.push(MerkleProofNode { hash, position });
         }
 
+        // leftover bytes that are too few for a full node
+        // if they start with a valid position byte, it's most likely a node that was cut short
+        if source.get_bytes_left() > 0 {
+            return match source.next_u8().and_then(MerkleProofNodePosition::from_u8) {
+                Some(_) => Err(MerkleProofError::TruncatedHash),
+                None => Err(MerkleProofError::TrailingBytes),
+            };
+        }
+
         Ok(Self {
             api,
             nodes,
             raw_leaf,
         })
     }
+
+    /// Serializes the proof in the same format `from_bytes` expects
+    pub fn to_bytes(&self) -> BoxedBytes {
+        let mut sink = ZeroCopySink::new();
+
+        sink.write_var_bytes(self.raw_leaf.as_slice());
+        for node in &self.nodes {
+            sink.write_u8(node.position.as_u8());
+            sink.write_hash(&node.hash);
+        }
+
+        sink.get_sink()
+    }
 }
 
 impl<CA: CryptoApi> MerkleProof<CA> {
     pub fn get_proof_root(&self) -> H256 {
-        let mut current_hash = self.hash_leaf(&self.raw_leaf);
+        let mut current_hash = self.get_leaf_hash();
 
         for node in &self.nodes {
             match node.position {
                 MerkleProofNodePosition::Left => {
-                    current_hash = self.hash_children(&node.hash, &current_hash);
+                    current_hash = hash_children(&self.api, &node.hash, &current_hash);
                 }
                 MerkleProofNodePosition::Right => {
-                    current_hash = self.hash_children(&current_hash, &node.hash);
+                    current_hash = hash_children(&self.api, &current_hash, &node.hash);
                 }
             }
         }
@@ -97,24 +174,34 @@ impl<CA: CryptoApi> MerkleProof<CA> {
         current_hash
     }
 
+    pub fn get_leaf_hash(&self) -> H256 {
+        hash_leaf(&self.api, self.raw_leaf.as_slice())
+    }
+
+    pub fn get_nodes(&self) -> &[MerkleProofNode] {
+        &self.nodes
+    }
+
     pub fn into_raw_leaf(self) -> BoxedBytes {
         self.raw_leaf
     }
+}
 
-    fn hash_leaf(&self, raw_leaf: &BoxedBytes) -> H256 {
-        let mut serialized = Vec::with_capacity(1 + raw_leaf.len());
-        serialized.push(MerkleProofNodePosition::Left.as_u8());
-        serialized.extend_from_slice(raw_leaf.as_slice());
+// hashing scheme used by the Poly merkle trees
 
-        self.api.sha256(&serialized)
-    }
+pub fn hash_leaf<CA: CryptoApi>(api: &CA, raw_leaf: &[u8]) -> H256 {
+    let mut serialized = Vec::with_capacity(1 + raw_leaf.len());
+    serialized.push(MerkleProofNodePosition::Left.as_u8());
+    serialized.extend_from_slice(raw_leaf);
 
-    fn hash_children(&self, left: &H256, right: &H256) -> H256 {
-        let mut serialized = Vec::with_capacity(1 + 2 * H256::len_bytes());
-        serialized.push(MerkleProofNodePosition::Right.as_u8());
-        serialized.extend_from_slice(left.as_bytes());
-        serialized.extend_from_slice(right.as_bytes());
+    api.sha256(&serialized)
+}
 
-        self.api.sha256(&serialized)
-    }
+pub fn hash_children<CA: CryptoApi>(api: &CA, left: &H256, right: &H256) -> H256 {
+    let mut serialized = Vec::with_capacity(1 + 2 * H256::len_bytes());
+    serialized.push(MerkleProofNodePosition::Right.as_u8());
+    serialized.extend_from_slice(left.as_bytes());
+    serialized.extend_from_slice(right.as_bytes());
+
+    api.sha256(&serialized)
 }
@@ -0,0 +1,83 @@
+use crate::{hash_children, hash_leaf};
+use elrond_wasm::api::CryptoApi;
+use elrond_wasm::types::{Vec, H256};
+
+/// Append-only Merkle Mountain Range, which only keeps the peaks of its perfect subtrees.
+/// Peaks are bagged from right to left, which makes the root identical to the root
+/// of a Poly (RFC 6962) merkle tree built over the same leaves.
+/// Proofs for any leaf can thus be generated off-chain with the `MerkleTreeBuilder`.
+pub struct MerkleMountainRange<CA>
+where
+    CA: CryptoApi,
+{
+    api: CA,
+    peaks: Vec<H256>,
+    size: u64,
+}
+
+impl<CA: CryptoApi> MerkleMountainRange<CA> {
+    pub fn new(api: CA) -> Self {
+        Self {
+            api,
+            peaks: Vec::new(),
+            size: 0,
+        }
+    }
+
+    /// Restores a range from its peaks (highest first) and number of leaves.
+    /// Returns `None` if the number of peaks does not match the size.
+    pub fn from_peaks(api: CA, peaks: Vec<H256>, size: u64) -> Option<Self> {
+        if peaks.len() != size.count_ones() as usize {
+            return None;
+        }
+
+        Some(Self { api, peaks, size })
+    }
+
+    /// Appends the leaf and returns its index
+    pub fn append(&mut self, raw_leaf: &[u8]) -> u64 {
+        let leaf_index = self.size;
+        let mut current_hash = hash_leaf(&self.api, raw_leaf);
+
+        // every trailing one bit of the size is a peak of the same height as the new node
+        let mut merged_size = self.size;
+        while merged_size & 1 == 1 {
+            if let Some(left_peak) = self.peaks.pop() {
+                current_hash = hash_children(&self.api, &left_peak, &current_hash);
+            }
+            merged_size >>= 1;
+        }
+
+        self.peaks.push(current_hash);
+        self.size += 1;
+
+        leaf_index
+    }
+
+    /// Root of the range. An empty range has the zero hash as root.
+    pub fn root(&self) -> H256 {
+        let mut peaks_iter = self.peaks.iter().rev();
+        let mut root = match peaks_iter.next() {
+            Some(last_peak) => last_peak.clone(),
+            None => return H256::zero(),
+        };
+
+        for peak in peaks_iter {
+            root = hash_children(&self.api, peak, &root);
+        }
+
+        root
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn get_peaks(&self) -> &[H256] {
+        &self.peaks
+    }
+
+    pub fn into_peaks(self) -> Vec<H256> {
+        self.peaks
+    }
+}
@@ -0,0 +1,76 @@
+use elrond_wasm::types::BoxedBytes;
+use elrond_wasm_debug::TxContext;
+
+use merkle_proof::*;
+
+fn serialized_proof(raw_leaf: &[u8], nodes: &[(u8, [u8; 32])]) -> BoxedBytes {
+    let mut serialized = Vec::new();
+    serialized.push(raw_leaf.len() as u8);
+    serialized.extend_from_slice(raw_leaf);
+
+    for (pos, hash) in nodes {
+        serialized.push(*pos);
+        serialized.extend_from_slice(&hash[..]);
+    }
+
+    BoxedBytes::from(serialized.as_slice())
+}
+
+fn decode_err(proof: &BoxedBytes) -> MerkleProofError {
+    match MerkleProof::from_bytes(TxContext::dummy(), proof) {
+        Ok(_) => panic!("Proof decoding should have failed"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn truncated_leaf_test() {
+    let proof = BoxedBytes::from(&[5u8, b'a', b'b'][..]);
+
+    assert_eq!(decode_err(&proof), MerkleProofError::TruncatedLeaf);
+}
+
+#[test]
+fn invalid_node_position_test() {
+    let proof = serialized_proof(b"leaf", &[(0, [1u8; 32]), (2, [2u8; 32])]);
+
+    assert_eq!(decode_err(&proof), MerkleProofError::InvalidNodePosition);
+}
+
+#[test]
+fn truncated_hash_test() {
+    let mut serialized = serialized_proof(b"leaf", &[(1, [1u8; 32])])
+        .as_slice()
+        .to_vec();
+    serialized.push(0);
+    serialized.extend_from_slice(&[3u8; 16]);
+
+    assert_eq!(
+        decode_err(&BoxedBytes::from(serialized.as_slice())),
+        MerkleProofError::TruncatedHash
+    );
+}
+
+#[test]
+fn trailing_bytes_test() {
+    let mut serialized = serialized_proof(b"leaf", &[(1, [1u8; 32])])
+        .as_slice()
+        .to_vec();
+    serialized.extend_from_slice(&[7u8; 4]);
+
+    assert_eq!(
+        decode_err(&BoxedBytes::from(serialized.as_slice())),
+        MerkleProofError::TrailingBytes
+    );
+}
+
+#[test]
+fn max_depth_test() {
+    let max_depth_nodes = vec![(0u8, [1u8; 32]); MAX_MERKLE_PROOF_DEPTH];
+    let proof = serialized_proof(b"leaf", &max_depth_nodes);
+    assert!(MerkleProof::from_bytes(TxContext::dummy(), &proof).is_ok());
+
+    let too_deep_nodes = vec![(0u8, [1u8; 32]); MAX_MERKLE_PROOF_DEPTH + 1];
+    let proof = serialized_proof(b"leaf", &too_deep_nodes);
+    assert_eq!(decode_err(&proof), MerkleProofError::MaxDepthExceeded);
+}
@@ -0,0 +1,83 @@
+use elrond_wasm::types::BoxedBytes;
+use elrond_wasm_debug::TxContext;
+
+use merkle_proof::builder::MerkleTreeBuilder;
+use merkle_proof::*;
+
+fn build_tree(nr_leaves: usize) -> MerkleTreeBuilder<TxContext> {
+    let mut builder = MerkleTreeBuilder::new(TxContext::dummy());
+    for i in 0..nr_leaves {
+        builder.add_leaf(format!("leaf{}", i).as_bytes());
+    }
+
+    builder
+}
+
+#[test]
+fn single_leaf_root_test() {
+    let api = TxContext::dummy();
+    let builder = build_tree(1);
+
+    assert_eq!(builder.root(), hash_leaf(&api, b"leaf0"));
+    assert!(builder.proof(0).unwrap().get_nodes().is_empty());
+    assert!(builder.proof(1).is_none());
+}
+
+#[test]
+fn unbalanced_tree_root_test() {
+    let api = TxContext::dummy();
+    let builder = build_tree(3);
+
+    let left = hash_children(&api, &hash_leaf(&api, b"leaf0"), &hash_leaf(&api, b"leaf1"));
+    let expected_root = hash_children(&api, &left, &hash_leaf(&api, b"leaf2"));
+
+    assert_eq!(builder.root(), expected_root);
+}
+
+#[test]
+fn serialized_proofs_roundtrip_test() {
+    for nr_leaves in 1..=9 {
+        let builder = build_tree(nr_leaves);
+        let root = builder.root();
+
+        for index in 0..nr_leaves {
+            let serialized = builder.serialized_proof(index).unwrap();
+            let proof = match MerkleProof::from_bytes(TxContext::dummy(), &serialized) {
+                Ok(proof) => proof,
+                Err(err) => panic!(
+                    "Proof deserialization error: {}",
+                    std::str::from_utf8(err.as_bytes()).unwrap()
+                ),
+            };
+
+            assert_eq!(
+                proof.get_proof_root(),
+                root,
+                "leaves: {}, index: {}",
+                nr_leaves,
+                index
+            );
+            assert_eq!(
+                proof.into_raw_leaf(),
+                BoxedBytes::from(format!("leaf{}", index).as_bytes())
+            );
+        }
+    }
+}
+
+#[test]
+fn multi_proof_test() {
+    let builder = build_tree(5);
+    let root = builder.root();
+
+    let proofs = builder.serialized_multi_proof(&[4, 0, 2]).unwrap();
+    assert_eq!(proofs.len(), 3);
+    for serialized in &proofs {
+        match MerkleProof::from_bytes(TxContext::dummy(), serialized) {
+            Ok(proof) => assert_eq!(proof.get_proof_root(), root),
+            Err(_) => panic!("Proof deserialization error"),
+        }
+    }
+
+    assert!(builder.serialized_multi_proof(&[0, 5]).is_none());
+}
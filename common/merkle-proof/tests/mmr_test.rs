@@ -0,0 +1,54 @@
+use elrond_wasm::types::H256;
+use elrond_wasm_debug::TxContext;
+
+use merkle_proof::builder::MerkleTreeBuilder;
+use merkle_proof::mmr::MerkleMountainRange;
+use merkle_proof::*;
+
+#[test]
+fn empty_range_test() {
+    let mmr = MerkleMountainRange::new(TxContext::dummy());
+
+    assert_eq!(mmr.root(), H256::zero());
+    assert_eq!(mmr.size(), 0);
+}
+
+#[test]
+fn root_matches_tree_builder_test() {
+    let mut mmr = MerkleMountainRange::new(TxContext::dummy());
+    let mut builder = MerkleTreeBuilder::new(TxContext::dummy());
+
+    for i in 0..17u64 {
+        let leaf = format!("tx{}", i);
+        assert_eq!(mmr.append(leaf.as_bytes()), i);
+        builder.add_leaf(leaf.as_bytes());
+
+        assert_eq!(mmr.root(), builder.root(), "leaves: {}", i + 1);
+        assert_eq!(mmr.get_peaks().len(), (i + 1).count_ones() as usize);
+    }
+
+    let root = mmr.root();
+    for index in 0..builder.len() {
+        let serialized = builder.serialized_proof(index).unwrap();
+        match MerkleProof::from_bytes(TxContext::dummy(), &serialized) {
+            Ok(proof) => assert_eq!(proof.get_proof_root(), root),
+            Err(_) => panic!("Proof deserialization error"),
+        }
+    }
+}
+
+#[test]
+fn restore_from_peaks_test() {
+    let mut mmr = MerkleMountainRange::new(TxContext::dummy());
+    for i in 0..5 {
+        mmr.append(format!("tx{}", i).as_bytes());
+    }
+    let root = mmr.root();
+    let peaks = mmr.into_peaks();
+
+    assert!(MerkleMountainRange::from_peaks(TxContext::dummy(), peaks.clone(), 6).is_none());
+
+    let mut restored = MerkleMountainRange::from_peaks(TxContext::dummy(), peaks, 5).unwrap();
+    assert_eq!(restored.root(), root);
+    assert_eq!(restored.append(b"tx5"), 5);
+}
@@ -0,0 +1,94 @@
+#![no_std]
+
+use access_control::Role;
+
+elrond_wasm::imports!();
+
+pub const PAUSE_ENDPOINT_NAME: &[u8] = b"pause";
+pub const UNPAUSE_ENDPOINT_NAME: &[u8] = b"unpause";
+
+/// Circuit breaker with separate flags for the inbound (other chain -> Elrond)
+/// and outbound (Elrond -> other chain) flows.
+/// Pausers can pause instantly, but only admins can unpause.
+#[elrond_wasm_derive::module]
+pub trait PauseModule: access_control::AccessControlModule {
+    // endpoints - admin-only
+
+    #[endpoint(unpause)]
+    fn unpause(&self, unpause_inbound: bool, unpause_outbound: bool) -> SCResult<()> {
+        self.require_role(Role::Admin)?;
+        require!(
+            unpause_inbound || unpause_outbound,
+            "Must unpause at least one flow"
+        );
+
+        if unpause_inbound {
+            self.inbound_paused().clear();
+        }
+        if unpause_outbound {
+            self.outbound_paused().clear();
+        }
+
+        self.emit_pause_changed_event();
+
+        Ok(())
+    }
+
+    // endpoints - pausers
+
+    #[endpoint(pause)]
+    fn pause(&self, pause_inbound: bool, pause_outbound: bool) -> SCResult<()> {
+        self.require_role(Role::Pauser)?;
+        require!(
+            pause_inbound || pause_outbound,
+            "Must pause at least one flow"
+        );
+
+        if pause_inbound {
+            self.inbound_paused().set(&true);
+        }
+        if pause_outbound {
+            self.outbound_paused().set(&true);
+        }
+
+        self.emit_pause_changed_event();
+
+        Ok(())
+    }
+
+    // private
+
+    fn require_inbound_not_paused(&self) -> SCResult<()> {
+        require!(!self.inbound_paused().get(), "Inbound transfers are paused");
+
+        Ok(())
+    }
+
+    fn require_outbound_not_paused(&self) -> SCResult<()> {
+        require!(
+            !self.outbound_paused().get(),
+            "Outbound transfers are paused"
+        );
+
+        Ok(())
+    }
+
+    fn emit_pause_changed_event(&self) {
+        self.pause_changed_event(self.inbound_paused().get(), self.outbound_paused().get());
+    }
+
+    // events
+
+    #[event("pauseChanged")]
+    fn pause_changed_event(&self, #[indexed] inbound_paused: bool, outbound_paused: bool);
+
+    // storage
+
+    #[view(isInboundPaused)]
+    #[storage_mapper("inboundPaused")]
+    fn inbound_paused(&self) -> SingleValueMapper<Self::Storage, bool>;
+
+    #[view(isOutboundPaused)]
+    #[storage_mapper("outboundPaused")]
+    fn outbound_paused(&self) -> SingleValueMapper<Self::Storage, bool>;
+}
@@ -1,6 +1,6 @@
 #![no_std]
 
-use elrond_wasm::api::{BigUintApi, CryptoApi};
+use elrond_wasm::api::CryptoApi;
 use elrond_wasm::elrond_codec::*;
 use elrond_wasm::types::{BoxedBytes, H256};
 
@@ -9,34 +9,55 @@ use zero_copy_source::*;
 
 elrond_wasm::derive_imports!();
 
+pub mod nft_transaction_args;
 pub mod to_merkle_value;
 pub mod transaction_args;
 
+pub use nft_transaction_args::*;
 pub use to_merkle_value::*;
 pub use transaction_args::*;
 
-#[derive(NestedDecode, NestedEncode, TopDecode, TopEncode, TypeAbi, PartialEq)]
+#[derive(NestedDecode, NestedEncode, TopDecode, TopEncode, TypeAbi, PartialEq, Clone, Copy)]
 pub enum TransactionStatus {
     None,
     Pending,
     InProgress,
     Executed,
     Rejected,
+    /// Inbound txs accepted by the destination application, which could not execute them,
+    /// and recorded them to be retried or refunded
+    Failed,
+}
+
+impl TransactionStatus {
+    /// Executed and Rejected are final. Failed txs may only become Executed, once the application retried them.
+    /// None is only the initial, unset status.
+    pub fn can_transition_to(&self, new_status: TransactionStatus) -> bool {
+        match (*self, new_status) {
+            (Self::None, Self::Pending) => true,
+            (Self::None, Self::Executed) | (Self::None, Self::Failed) => true,
+            (Self::Pending, Self::InProgress) => true,
+            (Self::Pending, Self::Executed) | (Self::Pending, Self::Rejected) => true,
+            (Self::InProgress, Self::Executed) | (Self::InProgress, Self::Rejected) => true,
+            (Self::Failed, Self::Executed) => true,
+            _ => false,
+        }
+    }
 }
 
 // Not using the built-in Address type for addresses, as not all chains have 32-byte addresses
 #[derive(TypeAbi)]
-pub struct Transaction<BigUint: BigUintApi> {
+pub struct Transaction {
     pub source_chain_tx_hash: H256,
     pub cross_chain_tx_id: BoxedBytes, // not used
     pub from_contract_address: BoxedBytes,
     pub to_chain_id: u64,
     pub to_contract_address: BoxedBytes,
     pub method_name: BoxedBytes,
-    pub method_args: TransactionArgs<BigUint>,
+    pub method_args: BoxedBytes, // raw args, decoded by the destination contract
 }
 
-impl<BigUint: BigUintApi> Transaction<BigUint> {
+impl Transaction {
     pub fn get_partial_serialized(&self) -> BoxedBytes {
         self.serialize_partial().get_sink()
     }
@@ -80,7 +101,10 @@ impl<BigUint: BigUintApi> Transaction<BigUint> {
             None => return Err(DecodeError::INPUT_TOO_SHORT),
         };
 
-        method_args = TransactionArgs::decode_from_source(source)?;
+        match source.next_var_bytes() {
+            Some(val) => method_args = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
 
         Ok(Transaction {
             source_chain_tx_hash,
@@ -99,7 +123,7 @@ impl<BigUint: BigUintApi> Transaction<BigUint> {
 }
 
 // private methods
-impl<BigUint: BigUintApi> Transaction<BigUint> {
+impl Transaction {
     fn serialize_partial(&self) -> ZeroCopySink {
         let mut sink = ZeroCopySink::new();
 
@@ -109,17 +133,17 @@ impl<BigUint: BigUintApi> Transaction<BigUint> {
         sink.write_var_bytes(self.to_contract_address.as_slice());
         sink.write_var_bytes(self.method_name.as_slice());
 
-        let _ = self.method_args.dep_encode(&mut sink);
+        sink.write_var_bytes(self.method_args.as_slice());
 
         sink
     }
 }
 
-impl<BigUint: BigUintApi> NestedEncode for Transaction<BigUint> {
+impl NestedEncode for Transaction {
     fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
         let mut sink = ZeroCopySink::new();
 
-        sink.write_hash(&self.source_chain_tx_hash);
+        sink.write_var_bytes(self.source_chain_tx_hash.as_bytes());
         sink.write_bytes(self.serialize_partial().get_sink().as_slice());
 
         dest.write(sink.get_sink().as_slice());
@@ -128,7 +152,7 @@ impl<BigUint: BigUintApi> NestedEncode for Transaction<BigUint> {
     }
 }
 
-impl<BigUint: BigUintApi> NestedDecode for Transaction<BigUint> {
+impl NestedDecode for Transaction {
     fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
         let mut source = ZeroCopySource::new(input.flush());
 
@@ -136,14 +160,14 @@ impl<BigUint: BigUintApi> NestedDecode for Transaction<BigUint> {
     }
 }
 
-impl<BigUint: BigUintApi> TopEncode for Transaction<BigUint> {
+impl TopEncode for Transaction {
     #[inline]
     fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
         top_encode_from_nested(self, output)
     }
 }
 
-impl<BigUint: BigUintApi> TopDecode for Transaction<BigUint> {
+impl TopDecode for Transaction {
     fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
         top_decode_from_nested(input)
     }
@@ -0,0 +1,165 @@
+use elrond_wasm::api::BigUintApi;
+use elrond_wasm::elrond_codec::*;
+use elrond_wasm::types::{BoxedBytes, Vec};
+
+use zero_copy_sink::*;
+use zero_copy_source::*;
+
+elrond_wasm::derive_imports!();
+
+/// Args for NFT/SFT transfers, i.e. ERC721/ERC1155 tokens on other chains.
+/// The token_id is the other chain's ID of the token inside its collection, not the Elrond nonce.
+/// from_address is an optional trailing field, only serialized if set. It's used to refund failed transfers.
+#[derive(TypeAbi, Clone)]
+pub struct NftTransactionArgs<BigUint: BigUintApi> {
+    pub asset_hash: BoxedBytes,
+    pub dest_address: BoxedBytes,
+    pub token_id: BigUint,
+    pub amount: BigUint,
+    pub attributes: BoxedBytes,
+    pub uris: Vec<BoxedBytes>,
+    pub from_address: BoxedBytes,
+}
+
+impl<BigUint: BigUintApi> NftTransactionArgs<BigUint> {
+    /// The length prefix delimits the args, as the optional trailing field is detected by the bytes left
+    pub fn decode_from_source(source: &mut ZeroCopySource) -> Result<Self, DecodeError> {
+        let args_len = match source.next_var_uint() {
+            Some(val) => val as usize,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_bytes(args_len) {
+            Some(raw_args) => Self::from_raw_bytes(raw_args.as_slice()),
+            None => Err(DecodeError::INPUT_TOO_SHORT),
+        }
+    }
+
+    /// Decodes the args from a Transaction's raw method_args, i.e. without the length prefix
+    pub fn from_raw_bytes(raw_args: &[u8]) -> Result<Self, DecodeError> {
+        let mut source = ZeroCopySource::new(raw_args);
+
+        Self::decode_fields(&mut source)
+    }
+
+    /// Serializes the args as a Transaction's raw method_args, i.e. without the length prefix
+    pub fn to_raw_bytes(&self) -> Result<BoxedBytes, EncodeError> {
+        let mut sink = ZeroCopySink::new();
+
+        sink.write_var_bytes(self.asset_hash.as_slice());
+        sink.write_var_bytes(self.dest_address.as_slice());
+        sink.write_u256(&self.token_id)?;
+        sink.write_u256(&self.amount)?;
+        sink.write_var_bytes(self.attributes.as_slice());
+
+        sink.write_var_uint(self.uris.len() as u64);
+        for uri in &self.uris {
+            sink.write_var_bytes(uri.as_slice());
+        }
+
+        if !self.from_address.is_empty() {
+            sink.write_var_bytes(self.from_address.as_slice());
+        }
+
+        Ok(sink.get_sink())
+    }
+
+    fn decode_fields(source: &mut ZeroCopySource) -> Result<Self, DecodeError> {
+        let asset_hash;
+        let dest_address;
+        let token_id;
+        let amount;
+        let attributes;
+        let uris_len;
+        let mut uris = Vec::new();
+        let mut from_address = BoxedBytes::empty();
+
+        match source.next_var_bytes() {
+            Some(val) => asset_hash = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_var_bytes() {
+            Some(val) => dest_address = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_u256::<BigUint>() {
+            Some(val) => token_id = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_u256::<BigUint>() {
+            Some(val) => amount = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_var_bytes() {
+            Some(val) => attributes = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_var_uint() {
+            Some(val) => uris_len = val,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        for _ in 0..uris_len {
+            match source.next_var_bytes() {
+                Some(val) => uris.push(val),
+                None => return Err(DecodeError::INPUT_TOO_SHORT),
+            };
+        }
+
+        if source.get_bytes_left() > 0 {
+            match source.next_var_bytes() {
+                Some(val) => from_address = val,
+                None => return Err(DecodeError::INPUT_TOO_SHORT),
+            };
+        }
+
+        Ok(Self {
+            asset_hash,
+            dest_address,
+            token_id,
+            amount,
+            attributes,
+            uris,
+            from_address,
+        })
+    }
+}
+
+impl<BigUint: BigUintApi> NestedEncode for NftTransactionArgs<BigUint> {
+    fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
+        let serialized = self.to_raw_bytes()?;
+
+        let mut sink = ZeroCopySink::new();
+        sink.write_var_bytes(serialized.as_slice());
+
+        dest.write(sink.get_sink().as_slice());
+
+        Ok(())
+    }
+}
+
+impl<BigUint: BigUintApi> NestedDecode for NftTransactionArgs<BigUint> {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let mut source = ZeroCopySource::new(input.flush());
+
+        Self::decode_from_source(&mut source)
+    }
+}
+
+impl<BigUint: BigUintApi> TopEncode for NftTransactionArgs<BigUint> {
+    #[inline]
+    fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
+        top_encode_from_nested(self, output)
+    }
+}
+
+impl<BigUint: BigUintApi> TopDecode for NftTransactionArgs<BigUint> {
+    fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
+        top_decode_from_nested(input)
+    }
+}
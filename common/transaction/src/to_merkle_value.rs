@@ -1,4 +1,3 @@
-use elrond_wasm::api::BigUintApi;
 use elrond_wasm::elrond_codec::*;
 use elrond_wasm::types::H256;
 
@@ -8,13 +7,13 @@ use zero_copy_source::*;
 elrond_wasm::derive_imports!();
 
 #[derive(TypeAbi)]
-pub struct ToMerkleValue<BigUint: BigUintApi> {
+pub struct ToMerkleValue {
     pub poly_tx_hash: H256,
     pub from_chain_id: u64,
-    pub tx: crate::Transaction<BigUint>,
+    pub tx: crate::Transaction,
 }
 
-impl<BigUint: BigUintApi> NestedEncode for ToMerkleValue<BigUint> {
+impl NestedEncode for ToMerkleValue {
     fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
         let mut sink = ZeroCopySink::new();
 
@@ -28,7 +27,7 @@ impl<BigUint: BigUintApi> NestedEncode for ToMerkleValue<BigUint> {
     }
 }
 
-impl<BigUint: BigUintApi> NestedDecode for ToMerkleValue<BigUint> {
+impl NestedDecode for ToMerkleValue {
     fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
         let mut source = ZeroCopySource::new(input.flush());
 
@@ -59,14 +58,14 @@ impl<BigUint: BigUintApi> NestedDecode for ToMerkleValue<BigUint> {
     }
 }
 
-impl<BigUint: BigUintApi> TopEncode for ToMerkleValue<BigUint> {
+impl TopEncode for ToMerkleValue {
     #[inline]
     fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
         top_encode_from_nested(self, output)
     }
 }
 
-impl<BigUint: BigUintApi> TopDecode for ToMerkleValue<BigUint> {
+impl TopDecode for ToMerkleValue {
     fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
         top_decode_from_nested(input)
     }
@@ -7,20 +7,60 @@ use zero_copy_source::*;
 
 elrond_wasm::derive_imports!();
 
+/// from_address and data are optional trailing fields, only serialized if any of them is set.
+/// They are passed to bridge receiver contracts, and ignored otherwise.
 #[derive(TypeAbi, Clone)]
 pub struct TransactionArgs<BigUint: BigUintApi> {
     pub asset_hash: BoxedBytes,
     pub dest_address: BoxedBytes,
     pub amount: BigUint,
+    pub from_address: BoxedBytes,
+    pub data: BoxedBytes,
 }
 
 impl<BigUint: BigUintApi> TransactionArgs<BigUint> {
+    /// The length prefix delimits the args, as the optional trailing fields are detected by the bytes left
     pub fn decode_from_source(source: &mut ZeroCopySource) -> Result<Self, DecodeError> {
+        let args_len = match source.next_var_uint() {
+            Some(val) => val as usize,
+            None => return Err(DecodeError::INPUT_TOO_SHORT),
+        };
+
+        match source.next_bytes(args_len) {
+            Some(raw_args) => Self::from_raw_bytes(raw_args.as_slice()),
+            None => Err(DecodeError::INPUT_TOO_SHORT),
+        }
+    }
+
+    /// Decodes the args from a Transaction's raw method_args, i.e. without the length prefix
+    pub fn from_raw_bytes(raw_args: &[u8]) -> Result<Self, DecodeError> {
+        let mut source = ZeroCopySource::new(raw_args);
+
+        Self::decode_fields(&mut source)
+    }
+
+    /// Serializes the args as a Transaction's raw method_args, i.e. without the length prefix
+    pub fn to_raw_bytes(&self) -> Result<BoxedBytes, EncodeError> {
+        let mut sink = ZeroCopySink::new();
+
+        sink.write_var_bytes(self.asset_hash.as_slice());
+        sink.write_var_bytes(self.dest_address.as_slice());
+        sink.write_u256(&self.amount)?;
+
+        if !self.from_address.is_empty() || !self.data.is_empty() {
+            sink.write_var_bytes(self.from_address.as_slice());
+            sink.write_var_bytes(self.data.as_slice());
+        }
+
+        Ok(sink.get_sink())
+    }
+
+    fn decode_fields(source: &mut ZeroCopySource) -> Result<Self, DecodeError> {
         let asset_hash;
         let dest_address;
         let amount;
-
-        let _ = source.next_var_uint(); // ignore serialized struct byte length
+        let mut from_address = BoxedBytes::empty();
+        let mut data = BoxedBytes::empty();
 
         match source.next_var_bytes() {
             Some(val) => asset_hash = val,
@@ -37,24 +77,33 @@ impl<BigUint: BigUintApi> TransactionArgs<BigUint> {
             None => return Err(DecodeError::INPUT_TOO_SHORT),
         };
 
+        if source.get_bytes_left() > 0 {
+            match source.next_var_bytes() {
+                Some(val) => from_address = val,
+                None => return Err(DecodeError::INPUT_TOO_SHORT),
+            };
+
+            match source.next_var_bytes() {
+                Some(val) => data = val,
+                None => return Err(DecodeError::INPUT_TOO_SHORT),
+            };
+        }
+
         Ok(Self {
             asset_hash,
             dest_address,
             amount,
+            from_address,
+            data,
         })
     }
 }
 
 impl<BigUint: BigUintApi> NestedEncode for TransactionArgs<BigUint> {
     fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
-        let mut sink = ZeroCopySink::new();
-
-        sink.write_var_bytes(self.asset_hash.as_slice());
-        sink.write_var_bytes(self.dest_address.as_slice());
-        sink.write_u256(&self.amount)?;
+        let serialized = self.to_raw_bytes()?;
 
-        let serialized = sink.get_sink();
-        sink = ZeroCopySink::new();
+        let mut sink = ZeroCopySink::new();
         sink.write_var_bytes(serialized.as_slice());
 
         dest.write(sink.get_sink().as_slice());